@@ -0,0 +1,104 @@
+mod cli_flags;
+mod module_state;
+
+use std::fs;
+
+use cli_flags::{get_params, Params};
+use module_state::{find_stateful_declarations, StateKind};
+
+use crate::shared::{
+    module_format::find_cjs_counterpart,
+    reporter::{get_line_and_column, Finding, Level, Reporter},
+    utils::{self, list_files_recursively},
+    value_objects::{AbsoluteOutputDir, AbsolutePackageDir},
+};
+
+pub fn run_by_args(args: &[String]) -> Result<(), String> {
+    let Params {
+        absolute_package_dir,
+        absolute_esm_output_dir,
+        absolute_cjs_output_dir,
+        reporter,
+        report_file,
+    } = get_params(args)?;
+
+    run_by_params(
+        &absolute_package_dir,
+        &absolute_esm_output_dir,
+        &absolute_cjs_output_dir,
+        reporter.as_deref(),
+        report_file.as_deref(),
+    )
+}
+
+pub fn run_by_params(
+    absolute_package_dir: &str,
+    absolute_esm_output_dir: &str,
+    absolute_cjs_output_dir: &str,
+    reporter: Option<&str>,
+    report_file: Option<&str>,
+) -> Result<(), String> {
+    let reporter = reporter.map(Reporter::new).transpose()?;
+    let package_dir = AbsolutePackageDir::new(absolute_package_dir)?;
+    let esm_output_dir = AbsoluteOutputDir::new(absolute_esm_output_dir)?;
+    let cjs_output_dir = AbsoluteOutputDir::new(absolute_cjs_output_dir)?;
+
+    if !esm_output_dir.is_package_dir_child(&package_dir)
+        || !cjs_output_dir.is_package_dir_child(&package_dir)
+    {
+        return Err("Output directory must be inside the package directory".to_string());
+    }
+
+    let mut findings: Vec<Finding> = vec![];
+
+    for esm_file_path in list_files_recursively(esm_output_dir.value(), &["js", "mjs"]) {
+        // Only modules shipped in both builds can be loaded twice.
+        let Some(cjs_file_path) = find_cjs_counterpart(
+            esm_output_dir.value(),
+            cjs_output_dir.value(),
+            &esm_file_path,
+        ) else {
+            continue;
+        };
+
+        let Ok(file_content) = fs::read_to_string(&esm_file_path) else {
+            continue;
+        };
+
+        let cjs_file_name = utils::relative(package_dir.value(), &cjs_file_path)
+            .to_string_lossy()
+            .replace('\\', "/");
+
+        for declaration in find_stateful_declarations(&file_content) {
+            let (rule_id, message) = match declaration.kind {
+                StateKind::MutableBinding => (
+                    "dual-package-state",
+                    format!(
+                        "`{}` is module-level mutable state, and `{}` keeps its own copy of it; a consumer that loads both builds sees them drift apart. Isolate the state in a CommonJS module both builds import, or make the ESM build a wrapper that re-exports the CommonJS one.",
+                        declaration.name, cjs_file_name
+                    ),
+                ),
+                StateKind::ExportedClass => (
+                    "dual-package-class",
+                    format!(
+                        "`{}` is an exported class, and `{}` defines another one; `instanceof` fails for instances created by the other build. Make the ESM build a wrapper that re-exports the CommonJS one, or isolate the class in a CommonJS module both builds import.",
+                        declaration.name, cjs_file_name
+                    ),
+                ),
+            };
+            let (line, column) = get_line_and_column(&file_content, declaration.offset);
+
+            findings.push(Finding {
+                rule_id: rule_id.to_string(),
+                level: Level::Warning,
+                message,
+                file_path: esm_file_path.clone(),
+                line,
+                column,
+            });
+        }
+    }
+
+    // The patterns are heuristics, so the hazards are warnings that never fail the command.
+    Reporter::emit(reporter, &findings, report_file)
+}
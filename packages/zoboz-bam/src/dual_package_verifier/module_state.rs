@@ -0,0 +1,88 @@
+use lazy_static::lazy_static;
+
+lazy_static! {
+    // NOTE: Only unindented declarations are matched, which is how compilers emit the top level.
+    static ref RE_MUTABLE_BINDING: regex::Regex =
+        regex::Regex::new(r"(?m)^(?:export\s+)?(?:let|var)\s+([\w$]+)").unwrap();
+    static ref RE_MUTABLE_CONTAINER: regex::Regex = regex::Regex::new(
+        r"(?m)^(?:export\s+)?const\s+([\w$]+)\s*=\s*(?:new\s+(?:Map|Set|WeakMap|WeakSet)\b|\[\s*\]|\{\s*\})"
+    )
+    .unwrap();
+    static ref RE_EXPORTED_CLASS: regex::Regex =
+        regex::Regex::new(r"(?m)^export\s+(?:default\s+)?(?:abstract\s+)?class\b\s*([\w$]*)")
+            .unwrap();
+    static ref RE_CLASS: regex::Regex =
+        regex::Regex::new(r"(?m)^(?:abstract\s+)?class\s+([\w$]+)").unwrap();
+    static ref RE_EXPORT_CLAUSE: regex::Regex =
+        regex::Regex::new(r"(?m)^export\s*\{([^}]*)\}(\s*from\b)?").unwrap();
+}
+
+#[derive(Clone, Copy, PartialEq, Eq)]
+pub(super) enum StateKind {
+    MutableBinding,
+    ExportedClass,
+}
+
+/// A top-level declaration that breaks once an ESM and a CJS copy of the module are both loaded.
+pub(super) struct StatefulDeclaration {
+    pub kind: StateKind,
+    pub name: String,
+    pub offset: usize,
+}
+
+/// Finds the module-level mutable state and the exported classes of an ESM file.
+pub(super) fn find_stateful_declarations(file_content: &str) -> Vec<StatefulDeclaration> {
+    let mut declarations = vec![];
+
+    for re in [&*RE_MUTABLE_BINDING, &*RE_MUTABLE_CONTAINER] {
+        for caps in re.captures_iter(file_content) {
+            declarations.push(StatefulDeclaration {
+                kind: StateKind::MutableBinding,
+                name: caps[1].to_string(),
+                offset: caps.get(1).unwrap().start(),
+            });
+        }
+    }
+
+    for caps in RE_EXPORTED_CLASS.captures_iter(file_content) {
+        let name = caps.get(1).unwrap();
+        // An anonymous `export default class` is pointed at by its keyword.
+        let (name, offset) = match name.as_str() {
+            "" => (
+                "default".to_string(),
+                caps.get(0).unwrap().start() + caps[0].find("class").unwrap(),
+            ),
+            _ => (name.as_str().to_string(), name.start()),
+        };
+        declarations.push(StatefulDeclaration {
+            kind: StateKind::ExportedClass,
+            name,
+            offset,
+        });
+    }
+
+    // Classes declared first and exported by a later `export { … }`.
+    let exported_local_names = get_exported_local_names(file_content);
+    for caps in RE_CLASS.captures_iter(file_content) {
+        if exported_local_names.contains(&&caps[1]) {
+            declarations.push(StatefulDeclaration {
+                kind: StateKind::ExportedClass,
+                name: caps[1].to_string(),
+                offset: caps.get(1).unwrap().start(),
+            });
+        }
+    }
+
+    declarations.sort_by_key(|declaration| declaration.offset);
+    declarations
+}
+
+/// The local names of `export { a, b as c }`; re-exports with `from` declare nothing locally.
+fn get_exported_local_names(file_content: &str) -> Vec<&str> {
+    RE_EXPORT_CLAUSE
+        .captures_iter(file_content)
+        .filter(|caps| caps.get(2).is_none())
+        .flat_map(|caps| caps.get(1).unwrap().as_str().split(','))
+        .filter_map(|specifier| specifier.split_whitespace().next())
+        .collect()
+}
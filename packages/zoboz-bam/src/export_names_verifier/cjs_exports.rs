@@ -0,0 +1,135 @@
+use std::{
+    collections::{BTreeSet, HashSet},
+    fs,
+    path::{Path, PathBuf},
+};
+
+use lazy_static::lazy_static;
+
+use super::resolve_relative_specifier;
+
+// The patterns follow what `cjs-module-lexer` detects, which is how Node.js finds the named
+// exports of a CommonJS module imported from ESM. Anything computed at runtime is invisible to it.
+lazy_static! {
+    static ref RE_EXPORTS_ASSIGNMENT: regex::Regex = regex::Regex::new(
+        r#"(?:^|[^.\w$])(?:module\.)?exports\s*(?:\.\s*([\w$]+)|\[\s*['"]([^'"]+)['"]\s*\])\s*(=+)"#
+    )
+    .unwrap();
+    static ref RE_DEFINE_PROPERTY: regex::Regex = regex::Regex::new(
+        r#"\bObject\.defineProperty\(\s*(?:module\.)?exports\s*,\s*['"]([^'"]+)['"]"#
+    )
+    .unwrap();
+    static ref RE_MODULE_EXPORTS_OBJECT: regex::Regex =
+        regex::Regex::new(r"(?:^|[^.\w$])module\.exports\s*=\s*\{").unwrap();
+    static ref RE_MODULE_EXPORTS_REQUIRE: regex::Regex = regex::Regex::new(
+        r#"(?:^|[^.\w$])module\.exports\s*=\s*require\(\s*['"]([^'"]+)['"]\s*\)"#
+    )
+    .unwrap();
+    static ref RE_EXPORT_STAR_HELPER: regex::Regex =
+        regex::Regex::new(r#"\b__export(?:Star)?\(\s*require\(\s*['"]([^'"]+)['"]\s*\)"#).unwrap();
+    static ref RE_REQUIRE_BINDING: regex::Regex = regex::Regex::new(
+        r#"\b([\w$]+)\s*=\s*(?:_interopRequireWildcard\(\s*)?require\(\s*['"]([^'"]+)['"]\s*\)"#
+    )
+    .unwrap();
+    static ref RE_KEYS_FOR_EACH: regex::Regex =
+        regex::Regex::new(r"\bObject\.keys\(\s*([\w$]+)\s*\)\.forEach\(").unwrap();
+    static ref RE_OBJECT_KEY: regex::Regex =
+        regex::Regex::new(r#"^(?:([\w$]+)|'([^']*)'|"([^"]*)")\s*(?::\s*[\w$]+(?:\.[\w$]+)*)?$"#)
+            .unwrap();
+    static ref RE_SPREAD_REQUIRE: regex::Regex =
+        regex::Regex::new(r#"^\.\.\.\s*require\(\s*['"]([^'"]+)['"]\s*\)$"#).unwrap();
+}
+
+/// Collects the names Node.js detects on a CommonJS file, following the re-exports of relative
+/// modules; `default` is always there, since it is `module.exports` itself.
+pub(super) fn collect_cjs_export_names(file_path: &Path) -> BTreeSet<String> {
+    let mut export_names = BTreeSet::from(["default".to_string()]);
+    collect_into(file_path, &mut HashSet::new(), &mut export_names);
+
+    export_names
+}
+
+fn collect_into(
+    file_path: &Path,
+    visited: &mut HashSet<PathBuf>,
+    export_names: &mut BTreeSet<String>,
+) {
+    if !visited.insert(file_path.to_path_buf()) {
+        return;
+    }
+
+    let Ok(file_content) = fs::read_to_string(file_path) else {
+        return;
+    };
+
+    let mut reexported_specifiers: Vec<String> = vec![];
+
+    // `==` and `===` are comparisons rather than assignments.
+    for caps in RE_EXPORTS_ASSIGNMENT.captures_iter(&file_content) {
+        if &caps[3] == "=" {
+            let export_name = caps.get(1).or(caps.get(2)).unwrap().as_str();
+            export_names.insert(export_name.to_string());
+        }
+    }
+
+    for caps in RE_DEFINE_PROPERTY.captures_iter(&file_content) {
+        export_names.insert(caps[1].to_string());
+    }
+
+    for found in RE_MODULE_EXPORTS_OBJECT.find_iter(&file_content) {
+        collect_object_literal(
+            &file_content[found.end()..],
+            export_names,
+            &mut reexported_specifiers,
+        );
+    }
+
+    for re in [&*RE_MODULE_EXPORTS_REQUIRE, &*RE_EXPORT_STAR_HELPER] {
+        for caps in re.captures_iter(&file_content) {
+            reexported_specifiers.push(caps[1].to_string());
+        }
+    }
+
+    // Babel copies every key of a required module: `Object.keys(_foo).forEach(...)`.
+    for caps in RE_KEYS_FOR_EACH.captures_iter(&file_content) {
+        if let Some(binding) = RE_REQUIRE_BINDING
+            .captures_iter(&file_content)
+            .find(|binding| binding[1] == caps[1])
+        {
+            reexported_specifiers.push(binding[2].to_string());
+        }
+    }
+
+    for specifier in reexported_specifiers {
+        if let Some(reexported_path) = resolve_relative_specifier(file_path, &specifier) {
+            collect_into(&reexported_path, visited, export_names);
+        }
+    }
+}
+
+/// Reads the keys of `module.exports = { a, 'b': b, ...require('c') }` up to the first property
+/// that isn't a shorthand, an identifier value or a spread `require`, where the lexer stops too.
+fn collect_object_literal(
+    text: &str,
+    export_names: &mut BTreeSet<String>,
+    reexported_specifiers: &mut Vec<String>,
+) {
+    let Some(end) = text.find('}') else {
+        return;
+    };
+
+    for property in text[..end].split(',').map(str::trim) {
+        if property.is_empty() {
+            continue;
+        }
+
+        if let Some(caps) = RE_SPREAD_REQUIRE.captures(property) {
+            reexported_specifiers.push(caps[1].to_string());
+        } else if let Some(caps) = RE_OBJECT_KEY.captures(property) {
+            let key = caps.get(1).or(caps.get(2)).or(caps.get(3)).unwrap();
+            export_names.insert(key.as_str().to_string());
+        } else {
+            return;
+        }
+    }
+}
@@ -0,0 +1,22 @@
+pub(super) struct Params {
+    pub absolute_package_dir: String,
+    pub absolute_esm_output_dir: String,
+    pub absolute_cjs_output_dir: String,
+}
+
+pub(super) fn get_params(args: &[String]) -> Result<Params, String> {
+    Ok(Params {
+        absolute_package_dir: get_required_value(args, "--absolute-package-dir")?,
+        absolute_esm_output_dir: get_required_value(args, "--absolute-esm-output-dir")?,
+        absolute_cjs_output_dir: get_required_value(args, "--absolute-cjs-output-dir")?,
+    })
+}
+
+fn get_value(args: &[String], flag: &str) -> Option<String> {
+    let index = args.iter().position(|arg| arg == flag)?;
+    args.get(index + 1).map(|value| value.to_string())
+}
+
+fn get_required_value(args: &[String], flag: &str) -> Result<String, String> {
+    get_value(args, flag).ok_or_else(|| format!("{} not found; use {} <path>", flag, flag))
+}
@@ -0,0 +1,83 @@
+use std::{
+    collections::{BTreeSet, HashSet},
+    fs,
+    path::{Path, PathBuf},
+};
+
+use lazy_static::lazy_static;
+
+use super::resolve_relative_specifier;
+
+lazy_static! {
+    static ref RE_EXPORT_DECLARATION: regex::Regex = regex::Regex::new(
+        r"(?m)^\s*export\s+(?:(?:async\s+)?function\b\s*\*?|(?:abstract\s+)?class\b|const\b|let\b|var\b)\s*([\w$]+)"
+    )
+    .unwrap();
+    static ref RE_EXPORT_DEFAULT: regex::Regex =
+        regex::Regex::new(r"(?m)^\s*export\s+default\b").unwrap();
+    static ref RE_EXPORT_CLAUSE: regex::Regex =
+        regex::Regex::new(r#"(?m)^\s*export\s*\{([^}]*)\}"#).unwrap();
+    static ref RE_EXPORT_STAR: regex::Regex = regex::Regex::new(
+        r#"(?m)^\s*export\s*\*\s*(?:as\s+([\w$]+)\s*)?from\s*['"]([^'"]+)['"]"#
+    )
+    .unwrap();
+}
+
+/// Collects the names an ESM file exports, following `export * from` into relative modules.
+/// Stars from packages can't be followed, so their names are left out.
+pub(super) fn collect_esm_export_names(file_path: &Path) -> BTreeSet<String> {
+    let mut export_names = BTreeSet::new();
+    collect_into(file_path, &mut HashSet::new(), &mut export_names);
+
+    export_names
+}
+
+fn collect_into(
+    file_path: &Path,
+    visited: &mut HashSet<PathBuf>,
+    export_names: &mut BTreeSet<String>,
+) {
+    if !visited.insert(file_path.to_path_buf()) {
+        return;
+    }
+
+    let Ok(file_content) = fs::read_to_string(file_path) else {
+        return;
+    };
+
+    for caps in RE_EXPORT_DECLARATION.captures_iter(&file_content) {
+        export_names.insert(caps[1].to_string());
+    }
+
+    if RE_EXPORT_DEFAULT.is_match(&file_content) {
+        export_names.insert("default".to_string());
+    }
+
+    // `export { a, b as c }` exports `a` and `c`, with or without a `from`.
+    for caps in RE_EXPORT_CLAUSE.captures_iter(&file_content) {
+        for specifier in caps[1].split(',') {
+            if let Some(export_name) = specifier.split_whitespace().last() {
+                export_names.insert(export_name.trim_matches(['"', '\'']).to_string());
+            }
+        }
+    }
+
+    for caps in RE_EXPORT_STAR.captures_iter(&file_content) {
+        match caps.get(1) {
+            Some(namespace_name) => {
+                export_names.insert(namespace_name.as_str().to_string());
+            }
+            None => {
+                let Some(reexported_path) = resolve_relative_specifier(file_path, &caps[2]) else {
+                    continue;
+                };
+
+                // A star re-export never includes the `default` of the other module.
+                let mut reexported_names = BTreeSet::new();
+                collect_into(&reexported_path, visited, &mut reexported_names);
+                reexported_names.remove("default");
+                export_names.extend(reexported_names);
+            }
+        }
+    }
+}
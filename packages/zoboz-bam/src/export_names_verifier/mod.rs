@@ -0,0 +1,182 @@
+mod cjs_exports;
+mod cli_flags;
+mod esm_exports;
+
+use std::{
+    collections::BTreeSet,
+    path::{Path, PathBuf},
+};
+
+use cjs_exports::collect_cjs_export_names;
+use cli_flags::{get_params, Params};
+use esm_exports::collect_esm_export_names;
+use serde_json::Value;
+
+use crate::shared::{
+    module_format::find_cjs_counterpart,
+    package_json_reader::get_package_json,
+    utils,
+    value_objects::{AbsoluteOutputDir, AbsolutePackageDir},
+};
+
+pub fn run_by_args(args: &[String]) -> Result<(), String> {
+    let Params {
+        absolute_package_dir,
+        absolute_esm_output_dir,
+        absolute_cjs_output_dir,
+    } = get_params(args)?;
+
+    run_by_params(
+        &absolute_package_dir,
+        &absolute_esm_output_dir,
+        &absolute_cjs_output_dir,
+    )
+}
+
+pub fn run_by_params(
+    absolute_package_dir: &str,
+    absolute_esm_output_dir: &str,
+    absolute_cjs_output_dir: &str,
+) -> Result<(), String> {
+    let package_dir = AbsolutePackageDir::new(absolute_package_dir)?;
+    let esm_output_dir = AbsoluteOutputDir::new(absolute_esm_output_dir)?;
+    let cjs_output_dir = AbsoluteOutputDir::new(absolute_cjs_output_dir)?;
+
+    if !esm_output_dir.is_package_dir_child(&package_dir)
+        || !cjs_output_dir.is_package_dir_child(&package_dir)
+    {
+        return Err("Output directory must be inside the package directory".to_string());
+    }
+
+    let entry_points = get_esm_entry_points(&package_dir, &esm_output_dir);
+    if entry_points.is_empty() {
+        return Err(format!(
+            "No entry point found in {}; point `exports`, `main` or `module` of package.json at it",
+            to_relative(&package_dir, esm_output_dir.value())
+        ));
+    }
+
+    let mut errors: Vec<String> = vec![];
+    let mut warnings: Vec<String> = vec![];
+
+    for esm_file_path in entry_points {
+        let esm_file_name = to_relative(&package_dir, &esm_file_path);
+        let Some(cjs_file_path) = find_cjs_counterpart(
+            esm_output_dir.value(),
+            cjs_output_dir.value(),
+            &esm_file_path,
+        ) else {
+            warnings.push(format!(
+                "`{}` has no CJS build at the same path in `{}`, so its exports weren't compared.",
+                esm_file_name,
+                to_relative(&package_dir, cjs_output_dir.value())
+            ));
+            continue;
+        };
+        let cjs_file_name = to_relative(&package_dir, &cjs_file_path);
+
+        let esm_export_names = collect_esm_export_names(&esm_file_path);
+        let cjs_export_names = collect_cjs_export_names(&cjs_file_path);
+
+        for export_name in esm_export_names.difference(&cjs_export_names) {
+            errors.push(format!(
+                "`{}` is exported by `{}`, but Node.js can't detect it in `{}`, so ESM code importing the CJS build can't use it by name. Assign it with `exports.{} = …` instead of copying it over at runtime.",
+                export_name, esm_file_name, cjs_file_name, export_name
+            ));
+        }
+
+        // `default` is always there in CJS, and `__esModule` is only an interop flag.
+        for export_name in cjs_export_names
+            .difference(&esm_export_names)
+            .filter(|export_name| *export_name != "default" && *export_name != "__esModule")
+        {
+            warnings.push(format!(
+                "`{}` is exported by `{}`, but not by `{}`.",
+                export_name, cjs_file_name, esm_file_name
+            ));
+        }
+    }
+
+    let output = errors
+        .iter()
+        .cloned()
+        .chain(warnings.iter().map(|warning| format!("warn: {}", warning)))
+        .collect::<Vec<_>>()
+        .join("\n");
+
+    if !errors.is_empty() {
+        return Err(output);
+    }
+
+    if !output.is_empty() {
+        println!("{}", output);
+    }
+
+    Ok(())
+}
+
+/// Resolves a relative `import` or `require` specifier to a file, the way the output's own
+/// specifiers are written: as is, with an extension, or as a directory index.
+pub(super) fn resolve_relative_specifier(file_path: &Path, specifier: &str) -> Option<PathBuf> {
+    if !specifier.starts_with("./") && !specifier.starts_with("../") {
+        return None;
+    }
+
+    let base_path = file_path.parent()?.join(specifier);
+
+    [
+        "",
+        ".js",
+        ".mjs",
+        ".cjs",
+        "/index.js",
+        "/index.mjs",
+        "/index.cjs",
+    ]
+    .iter()
+    .map(|suffix| PathBuf::from(format!("{}{}", base_path.to_string_lossy(), suffix)))
+    .find(|candidate| candidate.is_file())
+}
+
+/// The files in the ESM output that package.json points `exports`, `main` or `module` at.
+/// Subpath patterns have no single file, so they are left out.
+fn get_esm_entry_points(
+    package_dir: &AbsolutePackageDir,
+    esm_output_dir: &AbsoluteOutputDir,
+) -> BTreeSet<PathBuf> {
+    let package_json = get_package_json(package_dir);
+    let mut targets: Vec<String> = vec![];
+
+    if let Some(exports) = &package_json.exports {
+        collect_targets(exports, &mut targets);
+    }
+    targets.extend(package_json.main.clone());
+    targets.extend(package_json.module.clone());
+
+    targets
+        .iter()
+        .filter(|target| !target.contains('*'))
+        .filter_map(|target| utils::normalize_relative_path(target))
+        .map(|target| package_dir.value().join(target))
+        .filter(|target_path| {
+            target_path.is_file() && target_path.starts_with(esm_output_dir.value())
+        })
+        .collect()
+}
+
+fn collect_targets(value: &Value, targets: &mut Vec<String>) {
+    match value {
+        Value::String(target) => targets.push(target.clone()),
+        Value::Array(items) => items.iter().for_each(|item| collect_targets(item, targets)),
+        Value::Object(entries) => entries
+            .values()
+            .for_each(|item| collect_targets(item, targets)),
+        _ => {}
+    }
+}
+
+fn to_relative(package_dir: &AbsolutePackageDir, path: &Path) -> String {
+    utils::relative(package_dir.value(), path)
+        .to_string_lossy()
+        .replace('\\', "/")
+}
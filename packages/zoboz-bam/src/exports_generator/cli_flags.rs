@@ -0,0 +1,53 @@
+pub(super) struct Params {
+    pub absolute_package_dir: String,
+    pub absolute_source_dir: String,
+    pub absolute_esm_output_dir: Option<String>,
+    pub absolute_cjs_output_dir: Option<String>,
+    pub absolute_dts_output_dir: Option<String>,
+    pub entry_points: Vec<(String, String)>,
+    pub can_update_package_json: bool,
+    pub key_order: String,
+}
+
+pub(super) fn get_params(args: &[String]) -> Result<Params, String> {
+    Ok(Params {
+        absolute_package_dir: get_required_value(args, "--absolute-package-dir")?,
+        absolute_source_dir: get_required_value(args, "--absolute-source-dir")?,
+        absolute_esm_output_dir: get_value(args, "--absolute-esm-output-dir"),
+        absolute_cjs_output_dir: get_value(args, "--absolute-cjs-output-dir"),
+        absolute_dts_output_dir: get_value(args, "--absolute-dts-output-dir"),
+        entry_points: get_entry_points(args)?,
+        can_update_package_json: args.iter().any(|arg| arg == "--can-update-package-json"),
+        key_order: get_value(args, "--key-order").unwrap_or("append".to_string()),
+    })
+}
+
+fn get_value(args: &[String], flag: &str) -> Option<String> {
+    let index = args.iter().position(|arg| arg == flag)?;
+    args.get(index + 1).map(|value| value.to_string())
+}
+
+fn get_required_value(args: &[String], flag: &str) -> Result<String, String> {
+    get_value(args, flag).ok_or_else(|| format!("{} not found; use {} <path>", flag, flag))
+}
+
+fn get_entry_points(args: &[String]) -> Result<Vec<(String, String)>, String> {
+    args.iter()
+        .enumerate()
+        .filter(|(_, arg)| *arg == "--entry-point")
+        .map(|(index, _)| {
+            let value = args.get(index + 1).ok_or_else(|| {
+                "--entry-point is missing its value; use --entry-point <subpath>=<source file>"
+                    .to_string()
+            })?;
+
+            match value.split_once('=') {
+                Some((subpath, source_file)) => Ok((subpath.to_string(), source_file.to_string())),
+                None => Err(format!(
+                    "--entry-point '{}' is invalid; use --entry-point <subpath>=<source file>, e.g. --entry-point .=./src/index.ts",
+                    value
+                )),
+            }
+        })
+        .collect()
+}
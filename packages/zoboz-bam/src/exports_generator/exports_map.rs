@@ -0,0 +1,132 @@
+use std::path::Path;
+
+use serde_json::{Map, Value};
+
+use crate::shared::{
+    utils,
+    value_objects::{AbsoluteOutputDir, AbsolutePackageDir, AbsoluteSourceDir},
+};
+
+pub(super) struct OutputDirs {
+    pub esm: Option<AbsoluteOutputDir>,
+    pub cjs: Option<AbsoluteOutputDir>,
+    pub dts: Option<AbsoluteOutputDir>,
+}
+
+/// Builds the expected `main`, `module`, `types` and `exports` fields, in that order.
+/// Conditions are ordered `types`, `require`, `import`, `default` so that Node.js and
+/// TypeScript pick the declaration first and only fall back to `default` last.
+pub(super) fn generate(
+    package_dir: &AbsolutePackageDir,
+    source_dir: &AbsoluteSourceDir,
+    output_dirs: &OutputDirs,
+    entry_points: &[(String, String)],
+) -> Result<Map<String, Value>, String> {
+    validate_entry_points(entry_points)?;
+
+    let mut exports = Map::new();
+    let mut root_conditions = Map::new();
+
+    for (subpath, source_file) in entry_points {
+        let relative_source_file = get_relative_source_file(package_dir, source_dir, source_file)?;
+
+        let mut conditions = Map::new();
+
+        if let Some(dts) = &output_dirs.dts {
+            let target = get_target(package_dir, dts, &relative_source_file, "d.ts");
+            conditions.insert("types".to_string(), Value::String(target));
+        }
+
+        if let Some(cjs) = &output_dirs.cjs {
+            let target = get_target(package_dir, cjs, &relative_source_file, "js");
+            conditions.insert("require".to_string(), Value::String(target));
+        }
+
+        if let Some(esm) = &output_dirs.esm {
+            let target = get_target(package_dir, esm, &relative_source_file, "js");
+            conditions.insert("import".to_string(), Value::String(target));
+        }
+
+        let default_target = conditions
+            .get("require")
+            .or_else(|| conditions.get("import"))
+            .cloned();
+
+        if let Some(default_target) = default_target {
+            conditions.insert("default".to_string(), default_target);
+        }
+
+        if subpath == "." {
+            root_conditions = conditions.clone();
+        }
+
+        exports.insert(subpath.to_string(), Value::Object(conditions));
+    }
+
+    let mut fields = Map::new();
+
+    for (field, condition) in [
+        ("main", "require"),
+        ("module", "import"),
+        ("types", "types"),
+    ] {
+        if let Some(target) = root_conditions.get(condition) {
+            fields.insert(field.to_string(), target.clone());
+        }
+    }
+
+    fields.insert("exports".to_string(), Value::Object(exports));
+
+    Ok(fields)
+}
+
+fn validate_entry_points(entry_points: &[(String, String)]) -> Result<(), String> {
+    if !entry_points.iter().any(|(subpath, _)| subpath == ".") {
+        return Err(
+            "Entry points must have a \".\" entry; e.g. --entry-point .=./src/index.ts".to_string(),
+        );
+    }
+
+    for (subpath, _) in entry_points {
+        if subpath != "." && !subpath.starts_with("./") {
+            return Err(format!(
+                "Entry point '{}' must start with \"./\"; e.g. --entry-point ./extend=./src/extend.ts",
+                subpath
+            ));
+        }
+    }
+
+    Ok(())
+}
+
+fn get_relative_source_file(
+    package_dir: &AbsolutePackageDir,
+    source_dir: &AbsoluteSourceDir,
+    source_file: &str,
+) -> Result<String, String> {
+    let absolute_source_file = package_dir
+        .value()
+        .join(source_file.trim_start_matches("./"));
+
+    match absolute_source_file.strip_prefix(source_dir.value()) {
+        Ok(relative_source_file) => Ok(relative_source_file.to_string_lossy().to_string()),
+        Err(_) => Err(format!(
+            "Entry point '{}' must be inside the source directory '{}'",
+            source_file,
+            source_dir.value().to_string_lossy()
+        )),
+    }
+}
+
+fn get_target(
+    package_dir: &AbsolutePackageDir,
+    output_dir: &AbsoluteOutputDir,
+    relative_source_file: &str,
+    extension: &str,
+) -> String {
+    let output_file = output_dir
+        .value()
+        .join(Path::new(relative_source_file).with_extension(extension));
+
+    utils::ensure_relative_prefix(utils::relative(package_dir.value(), output_file))
+}
@@ -0,0 +1,139 @@
+mod cli_flags;
+mod exports_map;
+
+use std::fs;
+
+use cli_flags::{get_params, Params};
+use exports_map::OutputDirs;
+use serde_json::Value;
+
+use crate::shared::{
+    json_editor::{apply_change_sets, to_json_pointer, Change, ChangeSet, ChangeType, KeyOrder},
+    package_json_reader::get_package_json_string,
+    value_objects::{AbsoluteOutputDir, AbsolutePackageDir, AbsoluteSourceDir},
+};
+
+const GENERATED_FIELDS: &[&str] = &["main", "module", "types", "exports"];
+
+pub fn run_by_args(args: &[String]) -> Result<(), String> {
+    run_by_params(&get_params(args)?)
+}
+
+fn run_by_params(params: &Params) -> Result<(), String> {
+    let package_dir = AbsolutePackageDir::new(&params.absolute_package_dir)?;
+    let key_order = KeyOrder::new(&params.key_order)?;
+    let source_dir = AbsoluteSourceDir::new(&params.absolute_source_dir)?;
+    let output_dirs = OutputDirs {
+        esm: params
+            .absolute_esm_output_dir
+            .as_deref()
+            .map(AbsoluteOutputDir::new)
+            .transpose()?,
+        cjs: params
+            .absolute_cjs_output_dir
+            .as_deref()
+            .map(AbsoluteOutputDir::new)
+            .transpose()?,
+        dts: params
+            .absolute_dts_output_dir
+            .as_deref()
+            .map(AbsoluteOutputDir::new)
+            .transpose()?,
+    };
+
+    if output_dirs.esm.is_none() && output_dirs.cjs.is_none() && output_dirs.dts.is_none() {
+        return Err("No output directory given; use --absolute-esm-output-dir, --absolute-cjs-output-dir or --absolute-dts-output-dir".to_string());
+    }
+
+    if !source_dir.is_package_dir_child(&package_dir) {
+        return Err("Source directory must be inside the package directory".to_string());
+    }
+
+    for output_dir in [&output_dirs.esm, &output_dirs.cjs, &output_dirs.dts]
+        .into_iter()
+        .flatten()
+    {
+        if !output_dir.is_package_dir_child(&package_dir) {
+            return Err("Output directory must be inside the package directory".to_string());
+        }
+    }
+
+    let expected_fields = exports_map::generate(
+        &package_dir,
+        &source_dir,
+        &output_dirs,
+        &params.entry_points,
+    )?;
+
+    let package_json_content = get_package_json_string(&package_dir);
+    let package_json: Value = serde_json::from_str(&package_json_content)
+        .map_err(|e| format!("Failed to parse package.json: {}", e))?;
+
+    let change_sets: Vec<ChangeSet> = GENERATED_FIELDS
+        .iter()
+        .filter_map(|field| {
+            create_change_set(field, package_json.get(*field), expected_fields.get(*field))
+        })
+        .collect();
+
+    if change_sets.is_empty() {
+        return Ok(());
+    }
+
+    if params.can_update_package_json {
+        fs::write(
+            package_dir.value().join("package.json"),
+            apply_change_sets(&package_json_content, change_sets, key_order),
+        )
+        .expect("Failed to write package.json");
+
+        return Ok(());
+    }
+
+    let descriptions: Vec<String> = change_sets
+        .iter()
+        .map(|change_set| change_set.description.clone())
+        .collect();
+
+    Err(descriptions.join("\n"))
+}
+
+fn create_change_set(
+    field: &str,
+    actual: Option<&Value>,
+    expected: Option<&Value>,
+) -> Option<ChangeSet> {
+    // Compared by their serialized form, since the order of the conditions matters.
+    let actual_text = actual.map(|value| serde_json::to_string(value).unwrap());
+    let expected_text = expected.map(|value| serde_json::to_string(value).unwrap());
+
+    let (description, change_type) = match (&actual_text, &expected_text) {
+        (Some(actual_text), Some(expected_text)) if actual_text == expected_text => return None,
+        (None, None) => return None,
+        (Some(_), None) => (
+            format!("Remove `\"{}\"` from package.json", field),
+            ChangeType::Remove,
+        ),
+        (None, Some(expected_text)) => (
+            format!("Add `\"{}\": {}` to package.json", field, expected_text),
+            ChangeType::Add,
+        ),
+        (Some(_), Some(expected_text)) => (
+            format!(
+                "Change `\"{}\"` to `{}` in package.json",
+                field, expected_text
+            ),
+            ChangeType::Update,
+        ),
+    };
+
+    Some(ChangeSet {
+        description,
+        json_path: to_json_pointer(&[field]),
+        changes: vec![Change {
+            path: to_json_pointer(&[field]),
+            change_type,
+            value: expected.cloned(),
+        }],
+    })
+}
@@ -1,16 +1,32 @@
+pub mod dual_package_verifier;
+pub mod export_names_verifier;
+pub mod exports_generator;
+pub mod lockfile_verifier;
+pub mod output_cleaner;
+pub mod pack_preview;
 pub mod package_json_verifier;
+pub mod resolution_verifier;
 pub mod shared;
 pub mod specifiers_reformatter;
+pub mod type_enforcer;
 
 pub fn handle_command(args: &[String]) -> Result<(), String> {
     let command = args[0].as_str();
     match command {
         "reformat-specifiers" => specifiers_reformatter::run_by_args(args),
         "verify-package-json" => package_json_verifier::run_by_args(args),
+        "generate-exports" => exports_generator::run_by_args(args),
+        "verify-resolution" => resolution_verifier::run_by_args(args),
+        "pack-preview" => pack_preview::run_by_args(args),
+        "verify-lockfile" => lockfile_verifier::run_by_args(args),
+        "enforce-type" => type_enforcer::run_by_args(args),
+        "clean-output" => output_cleaner::run_by_args(args),
+        "verify-dual-package" => dual_package_verifier::run_by_args(args),
+        "verify-export-names" => export_names_verifier::run_by_args(args),
         _ => Err(format!("Invalid command: {}", command)),
     }
 }
 
 pub fn tokenize_input(input: &str) -> Vec<String> {
-    shell_words::split(&input).unwrap_or_else(|_| vec![])
+    shell_words::split(input).unwrap_or_else(|_| vec![])
 }
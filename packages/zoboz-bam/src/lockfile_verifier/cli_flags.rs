@@ -0,0 +1,30 @@
+pub(super) fn get_params(args: &[String]) -> Result<(String, Vec<String>), String> {
+    let absolute_package_dir = get_absolute_package_dir(args)?;
+    let absolute_output_dirs = get_absolute_output_dirs(args);
+
+    Ok((absolute_package_dir, absolute_output_dirs))
+}
+
+fn get_absolute_package_dir(args: &[String]) -> Result<String, String> {
+    let absolute_package_dir = args.iter().position(|arg| arg == "--absolute-package-dir");
+    let absolute_package_dir = match absolute_package_dir {
+        Some(index) => args.get(index + 1),
+        None => None,
+    };
+
+    match absolute_package_dir {
+        Some(value) => Ok(value.to_string()),
+        None => {
+            Err("--absolute-package-dir not found; use --absolute-package-dir <path>".to_owned())
+        }
+    }
+}
+
+fn get_absolute_output_dirs(args: &[String]) -> Vec<String> {
+    args.iter()
+        .enumerate()
+        .filter(|(_, arg)| *arg == "--absolute-output-dir")
+        .filter_map(|(index, _)| args.get(index + 1))
+        .map(|value| value.to_string())
+        .collect()
+}
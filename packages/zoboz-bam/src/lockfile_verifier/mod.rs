@@ -0,0 +1,126 @@
+mod cli_flags;
+mod package_lock;
+
+use cli_flags::get_params;
+use package_lock::{get_version, PackageLock};
+
+use crate::shared::{
+    package_json_reader::get_package_json,
+    semver_range::{Range, Version},
+    specifiers_scanner::collect_runtime_package_names,
+    value_objects::{AbsoluteOutputDir, AbsolutePackageDir},
+};
+
+pub fn run_by_args(args: &[String]) -> Result<(), String> {
+    let (absolute_package_dir, absolute_output_dirs) = get_params(args)?;
+
+    run_by_params(&absolute_package_dir, &absolute_output_dirs)
+}
+
+pub fn run_by_params(
+    absolute_package_dir: &str,
+    absolute_output_dirs: &[String],
+) -> Result<(), String> {
+    let package_dir = AbsolutePackageDir::new(absolute_package_dir)?;
+    let absolute_output_dirs = absolute_output_dirs
+        .iter()
+        .map(|absolute_output_dir| AbsoluteOutputDir::new(absolute_output_dir))
+        .collect::<Result<Vec<_>, _>>()?;
+
+    let package_json = get_package_json(&package_dir);
+    let package_lock = PackageLock::find(&package_dir)?;
+    let location = package_lock.get_location(package_dir.value());
+
+    let mut problems: Vec<String> = vec![];
+
+    for (field, dependencies) in [
+        ("dependencies", &package_json.dependencies),
+        ("devDependencies", &package_json.dev_dependencies),
+        ("optionalDependencies", &package_json.optional_dependencies),
+        ("peerDependencies", &package_json.peer_dependencies),
+    ] {
+        let mut package_names: Vec<&String> = dependencies.keys().collect();
+        package_names.sort();
+
+        for package_name in package_names {
+            let declared_range = &dependencies[package_name];
+            // Tags, URLs and protocols like `workspace:` aren't ranges, so there is nothing to compare.
+            let Some(range) = Range::parse(get_range(declared_range)) else {
+                continue;
+            };
+
+            let Some(entry) = package_lock.resolve(&location, package_name) else {
+                // Optional and peer dependencies are allowed not to be installed.
+                if field == "dependencies" || field == "devDependencies" {
+                    problems.push(format!(
+                        "`{}` from `{}` is missing from package-lock.json; run `npm install` to update it.",
+                        package_name, field
+                    ));
+                }
+                continue;
+            };
+
+            let Some(locked_version) = get_version(entry) else {
+                continue;
+            };
+
+            if !Version::parse(locked_version)
+                .is_some_and(|version| range.is_satisfied_by(&version))
+            {
+                problems.push(format!(
+                    "`{}` is locked at `{}`, which doesn't satisfy `{}` from `{}`; run `npm install` to update it.",
+                    package_name, locked_version, declared_range, field
+                ));
+            }
+        }
+    }
+
+    for package_name in collect_runtime_package_names(&absolute_output_dirs) {
+        if package_json.name.as_deref() == Some(package_name.as_str())
+            || package_json.dependencies.contains_key(&package_name)
+            || package_json.dev_dependencies.contains_key(&package_name)
+            || package_json
+                .optional_dependencies
+                .contains_key(&package_name)
+            || package_json.peer_dependencies.contains_key(&package_name)
+        {
+            continue;
+        }
+
+        // Imports that resolve to nothing at all are for `verify-package-json` to report.
+        if package_lock.resolve(&location, &package_name).is_none() {
+            continue;
+        }
+
+        let dependents = package_lock.get_dependents(&package_name);
+        problems.push(format!(
+            "`{}` is imported by the output but only installed as a dependency of {}; consumers may not get it. Declare it in `dependencies` or `peerDependencies`.",
+            package_name,
+            match dependents.is_empty() {
+                true => "another package".to_string(),
+                false => dependents
+                    .iter()
+                    .map(|dependent| format!("`{}`", dependent))
+                    .collect::<Vec<_>>()
+                    .join(", "),
+            }
+        ));
+    }
+
+    if !problems.is_empty() {
+        return Err(problems.join("\n"));
+    }
+
+    Ok(())
+}
+
+/// `npm:other@^1.0.0` installs an alias, whose range is after the last `@`.
+fn get_range(declared_range: &str) -> &str {
+    match declared_range.strip_prefix("npm:") {
+        Some(aliased) => aliased
+            .rfind('@')
+            .filter(|index| *index > 0)
+            .map_or("*", |index| &aliased[index + 1..]),
+        None => declared_range,
+    }
+}
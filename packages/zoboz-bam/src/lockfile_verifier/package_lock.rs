@@ -0,0 +1,133 @@
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use serde_json::{Map, Value};
+
+use crate::shared::utils;
+use crate::shared::value_objects::AbsolutePackageDir;
+
+/// The `packages` of a v2 or v3 `package-lock.json`, keyed by their location relative to the
+/// lockfile, e.g. `node_modules/foo/node_modules/bar`. The root package is at `""`.
+pub(super) struct PackageLock {
+    dir: PathBuf,
+    packages: Map<String, Value>,
+}
+
+impl PackageLock {
+    /// Finds the closest `package-lock.json`, which sits at the root of a workspace.
+    pub(super) fn find(package_dir: &AbsolutePackageDir) -> Result<Self, String> {
+        let Some(dir) = package_dir
+            .value()
+            .ancestors()
+            .find(|dir| dir.join("package-lock.json").is_file())
+        else {
+            return Err(format!(
+                "No package-lock.json found in '{}' or any of its parents",
+                package_dir.value().to_string_lossy()
+            ));
+        };
+
+        let content = fs::read_to_string(dir.join("package-lock.json"))
+            .map_err(|e| format!("Failed to read package-lock.json: {}", e))?;
+        let package_lock: Value = serde_json::from_str(&content)
+            .map_err(|e| format!("Failed to parse package-lock.json: {}", e))?;
+
+        // Version 1 only has the nested `dependencies` tree, which npm 7 and later rewrite on install.
+        match package_lock.get("lockfileVersion").and_then(Value::as_u64) {
+            Some(2 | 3) => {}
+            version => {
+                return Err(format!(
+                    "lockfileVersion {} of package-lock.json is not supported; run `npm install` with npm 7 or later to upgrade it to version 3",
+                    version.map_or("(missing)".to_string(), |version| version.to_string())
+                ))
+            }
+        }
+
+        Ok(Self {
+            dir: dir.to_path_buf(),
+            packages: package_lock
+                .get("packages")
+                .and_then(Value::as_object)
+                .cloned()
+                .unwrap_or_default(),
+        })
+    }
+
+    /// Where a directory is in the lockfile, `""` for the lockfile's own directory.
+    pub(super) fn get_location(&self, dir: &Path) -> String {
+        utils::relative(&self.dir, dir)
+            .to_string_lossy()
+            .replace('\\', "/")
+    }
+
+    /// Resolves a package the way Node.js does from `location`: in its `node_modules`, then in the
+    /// ones of every directory above it. Links to workspace packages are followed.
+    pub(super) fn resolve(&self, location: &str, package_name: &str) -> Option<&Value> {
+        let mut location = location;
+
+        loop {
+            let candidate = match location {
+                "" => format!("node_modules/{}", package_name),
+                location => format!("{}/node_modules/{}", location, package_name),
+            };
+
+            if let Some(entry) = self.packages.get(&candidate) {
+                return Some(self.follow_link(entry));
+            }
+
+            if location.is_empty() {
+                return None;
+            }
+            location = location.rsplit_once('/').map_or("", |(parent, _)| parent);
+        }
+    }
+
+    /// Names of the packages that depend on the given one, sorted.
+    pub(super) fn get_dependents(&self, package_name: &str) -> Vec<String> {
+        let mut dependents: Vec<String> = self
+            .packages
+            .iter()
+            .filter(|(_, entry)| {
+                ["dependencies", "optionalDependencies", "peerDependencies"]
+                    .iter()
+                    .any(|field| {
+                        entry
+                            .get(field)
+                            .and_then(Value::as_object)
+                            .is_some_and(|dependencies| dependencies.contains_key(package_name))
+                    })
+            })
+            .map(|(location, entry)| {
+                entry
+                    .get("name")
+                    .and_then(Value::as_str)
+                    .map(|name| name.to_string())
+                    .unwrap_or_else(|| {
+                        location
+                            .rsplit_once("node_modules/")
+                            .map_or(location.as_str(), |(_, name)| name)
+                            .to_string()
+                    })
+            })
+            .collect();
+
+        dependents.sort();
+        dependents.dedup();
+        dependents
+    }
+
+    fn follow_link<'a>(&'a self, entry: &'a Value) -> &'a Value {
+        match entry.get("link").and_then(Value::as_bool) {
+            Some(true) => entry
+                .get("resolved")
+                .and_then(Value::as_str)
+                .and_then(|resolved| self.packages.get(resolved))
+                .unwrap_or(entry),
+            _ => entry,
+        }
+    }
+}
+
+pub(super) fn get_version(entry: &Value) -> Option<&str> {
+    entry.get("version").and_then(Value::as_str)
+}
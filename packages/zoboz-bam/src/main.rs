@@ -20,8 +20,16 @@ fn main() {
 fn console_mode() {
     println!("Zoboz Bam v{}", env!("CARGO_PKG_VERSION"));
     println!("Available Commands:");
-    println!("  reformat-specifiers --absolute-package-dir string --absolute-source-dir string --absolute-output-dir string --output-format dts|esm|cjs");
-    println!("  verify-package-json --absolute-package-dir string [--can-update-package-json]");
+    println!("  reformat-specifiers --absolute-package-dir string --absolute-source-dir string --absolute-output-dir string --output-format dts|esm|cjs [--reporter sarif|github] [--report-file string]");
+    println!("  verify-package-json (--absolute-package-dir string [--absolute-output-dir string]... | --workspace-root string [--relative-output-dir string]...) [--can-update-package-json] [--diff] [--prefer-peer-dependencies] [--key-order append|sort-package-json] [--format text|json] [--reporter sarif|github] [--report-file string]");
+    println!("  generate-exports --absolute-package-dir string --absolute-source-dir string [--absolute-esm-output-dir string] [--absolute-cjs-output-dir string] [--absolute-dts-output-dir string] --entry-point subpath=source-file... [--can-update-package-json] [--key-order append|sort-package-json]");
+    println!("  verify-resolution --absolute-package-dir string [--modes node10,node16-cjs,node16-esm,bundler]");
+    println!("  pack-preview --absolute-package-dir string [--out string]");
+    println!("  enforce-type --absolute-package-dir string [--absolute-esm-output-dir string] [--absolute-cjs-output-dir string]");
+    println!("  clean-output --absolute-package-dir string --absolute-output-dir string... [--preserve glob]... [--dry-run]");
+    println!("  verify-dual-package --absolute-package-dir string --absolute-esm-output-dir string --absolute-cjs-output-dir string [--reporter sarif|github] [--report-file string]");
+    println!("  verify-export-names --absolute-package-dir string --absolute-esm-output-dir string --absolute-cjs-output-dir string");
+    println!("  verify-lockfile --absolute-package-dir string [--absolute-output-dir string]...");
     println!("  exit");
 
     loop {
@@ -0,0 +1,42 @@
+pub(super) struct Params {
+    pub absolute_package_dir: String,
+    pub absolute_output_dirs: Vec<String>,
+    pub is_dry_run: bool,
+    pub preserved_patterns: Vec<String>,
+}
+
+pub(super) fn get_params(args: &[String]) -> Result<Params, String> {
+    Ok(Params {
+        absolute_package_dir: get_required_value(args, "--absolute-package-dir")?,
+        absolute_output_dirs: get_required_values(args, "--absolute-output-dir")?,
+        is_dry_run: args.iter().any(|arg| arg == "--dry-run"),
+        preserved_patterns: get_values(args, "--preserve"),
+    })
+}
+
+fn get_value(args: &[String], flag: &str) -> Option<String> {
+    let index = args.iter().position(|arg| arg == flag)?;
+    args.get(index + 1).map(|value| value.to_string())
+}
+
+fn get_required_value(args: &[String], flag: &str) -> Result<String, String> {
+    get_value(args, flag).ok_or_else(|| format!("{} not found; use {} <path>", flag, flag))
+}
+
+fn get_values(args: &[String], flag: &str) -> Vec<String> {
+    args.iter()
+        .enumerate()
+        .filter(|(_, arg)| *arg == flag)
+        .filter_map(|(index, _)| args.get(index + 1))
+        .map(|value| value.to_string())
+        .collect()
+}
+
+fn get_required_values(args: &[String], flag: &str) -> Result<Vec<String>, String> {
+    let values = get_values(args, flag);
+
+    match values.is_empty() {
+        true => Err(format!("{} not found; use {} <path>", flag, flag)),
+        false => Ok(values),
+    }
+}
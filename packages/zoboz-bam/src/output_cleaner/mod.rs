@@ -0,0 +1,232 @@
+mod cli_flags;
+
+use std::fs;
+use std::path::Path;
+
+use cli_flags::{get_params, Params};
+
+use crate::shared::{
+    glob_matcher::glob_to_regex,
+    type_marker::is_type_marker,
+    utils,
+    value_objects::{AbsoluteOutputDir, AbsolutePackageDir},
+};
+
+pub fn run_by_args(args: &[String]) -> Result<(), String> {
+    let Params {
+        absolute_package_dir,
+        absolute_output_dirs,
+        is_dry_run,
+        preserved_patterns,
+    } = get_params(args)?;
+
+    run_by_params(
+        &absolute_package_dir,
+        &absolute_output_dirs,
+        is_dry_run,
+        &preserved_patterns,
+    )
+}
+
+/// Empties the output directories, keeping the directories themselves and the files matching the
+/// preserved patterns (globs relative to each output directory). Every directory is checked before
+/// anything gets removed.
+pub fn run_by_params(
+    absolute_package_dir: &str,
+    absolute_output_dirs: &[String],
+    is_dry_run: bool,
+    preserved_patterns: &[String],
+) -> Result<(), String> {
+    let package_dir = AbsolutePackageDir::new(absolute_package_dir)?;
+    let preserved_regexes: Vec<regex::Regex> = preserved_patterns
+        .iter()
+        .map(|pattern| glob_to_regex(pattern.trim_start_matches("./")))
+        .collect();
+
+    let mut output_dirs = vec![];
+    for absolute_output_dir in absolute_output_dirs {
+        // A directory that doesn't exist has nothing to clean.
+        if Path::new(absolute_output_dir).is_absolute() && !Path::new(absolute_output_dir).exists()
+        {
+            continue;
+        }
+
+        let output_dir = AbsoluteOutputDir::new(absolute_output_dir)?;
+        check_is_cleanable(&package_dir, &output_dir, &preserved_regexes)?;
+        output_dirs.push(output_dir);
+    }
+
+    let mut removed_paths: Vec<String> = vec![];
+    for output_dir in &output_dirs {
+        let relative_output_dir = to_relative(&package_dir, output_dir.value());
+        let mut removed_output_paths = vec![];
+
+        clean_dir(
+            output_dir.value(),
+            "",
+            &preserved_regexes,
+            is_dry_run,
+            &mut removed_output_paths,
+        )
+        .map_err(|e| format!("Failed to clean {}: {}", relative_output_dir, e))?;
+
+        removed_paths.extend(
+            removed_output_paths
+                .into_iter()
+                .map(|removed_path| format!("{}/{}", relative_output_dir, removed_path)),
+        );
+    }
+
+    if is_dry_run && !removed_paths.is_empty() {
+        println!("{}", removed_paths.join("\n"));
+    }
+
+    Ok(())
+}
+
+/// Refuses anything that doesn't look like an output directory, since cleaning it can't be undone.
+/// The directories nested in it are held to the same checks, unless they are preserved.
+fn check_is_cleanable(
+    package_dir: &AbsolutePackageDir,
+    output_dir: &AbsoluteOutputDir,
+    preserved_regexes: &[regex::Regex],
+) -> Result<(), String> {
+    let display_path = output_dir.value().to_string_lossy();
+
+    if output_dir.value() == package_dir.value() {
+        return Err(format!(
+            "Refusing to clean {}: it is the package directory itself",
+            display_path
+        ));
+    }
+
+    if !output_dir.is_package_dir_child(package_dir) {
+        return Err(format!(
+            "Refusing to clean {}: it is outside the package directory",
+            display_path
+        ));
+    }
+
+    check_looks_like_output(output_dir.value())?;
+    check_nested_dirs(output_dir.value(), "", preserved_regexes)
+}
+
+/// Walks the directories the way `clean_dir` does, checking each one it would empty.
+fn check_nested_dirs(
+    output_dir: &Path,
+    relative_dir: &str,
+    preserved_regexes: &[regex::Regex],
+) -> Result<(), String> {
+    let dir = output_dir.join(relative_dir);
+    let read_error = |e: std::io::Error| format!("Failed to read {}: {}", dir.to_string_lossy(), e);
+    let mut entries = fs::read_dir(&dir)
+        .and_then(|entries| entries.collect::<Result<Vec<_>, _>>())
+        .map_err(read_error)?;
+    entries.sort_by_key(|entry| entry.file_name());
+
+    for entry in entries {
+        let file_name = entry.file_name().to_string_lossy().to_string();
+        let relative_path = match relative_dir {
+            "" => file_name,
+            relative_dir => format!("{}/{}", relative_dir, file_name),
+        };
+
+        if !entry.file_type().map_err(read_error)?.is_dir()
+            || preserved_regexes
+                .iter()
+                .any(|regex| regex.is_match(&relative_path))
+        {
+            continue;
+        }
+
+        check_looks_like_output(&entry.path())?;
+        check_nested_dirs(output_dir, &relative_path, preserved_regexes)?;
+    }
+
+    Ok(())
+}
+
+fn check_looks_like_output(dir: &Path) -> Result<(), String> {
+    let display_path = dir.to_string_lossy();
+
+    // Type markers are the only package.json an output directory is expected to have.
+    if dir.join("package.json").exists() && !is_type_marker(dir) {
+        return Err(format!(
+            "Refusing to clean {}: it has a package.json, so it looks like a package rather than an output directory",
+            display_path
+        ));
+    }
+
+    if dir.join("src").exists() {
+        return Err(format!(
+            "Refusing to clean {}: it has a `src` directory, so it looks like it holds source code",
+            display_path
+        ));
+    }
+
+    Ok(())
+}
+
+/// Removes everything under `relative_dir` that isn't preserved, deepest first, and tells whether
+/// the directory ended up empty. Symlinks are removed rather than followed.
+fn clean_dir(
+    output_dir: &Path,
+    relative_dir: &str,
+    preserved_regexes: &[regex::Regex],
+    is_dry_run: bool,
+    removed_paths: &mut Vec<String>,
+) -> std::io::Result<bool> {
+    let mut entries =
+        fs::read_dir(output_dir.join(relative_dir))?.collect::<Result<Vec<_>, _>>()?;
+    entries.sort_by_key(|entry| entry.file_name());
+
+    let mut is_empty = true;
+
+    for entry in entries {
+        let file_name = entry.file_name().to_string_lossy().to_string();
+        let relative_path = match relative_dir {
+            "" => file_name,
+            relative_dir => format!("{}/{}", relative_dir, file_name),
+        };
+
+        // A preserved directory keeps everything in it.
+        if preserved_regexes
+            .iter()
+            .any(|regex| regex.is_match(&relative_path))
+        {
+            is_empty = false;
+            continue;
+        }
+
+        if entry.file_type()?.is_dir() {
+            if !clean_dir(
+                output_dir,
+                &relative_path,
+                preserved_regexes,
+                is_dry_run,
+                removed_paths,
+            )? {
+                is_empty = false;
+                continue;
+            }
+
+            if !is_dry_run {
+                fs::remove_dir(entry.path())?;
+            }
+            removed_paths.push(format!("{}/", relative_path));
+        } else {
+            if !is_dry_run {
+                fs::remove_file(entry.path())?;
+            }
+            removed_paths.push(relative_path);
+        }
+    }
+
+    Ok(is_empty)
+}
+
+fn to_relative(package_dir: &AbsolutePackageDir, path: &Path) -> String {
+    utils::relative(package_dir.value(), path)
+        .to_string_lossy()
+        .replace('\\', "/")
+}
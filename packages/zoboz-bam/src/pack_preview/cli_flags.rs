@@ -0,0 +1,29 @@
+pub(super) fn get_params(args: &[String]) -> Result<(String, Option<String>), String> {
+    let absolute_package_dir = get_absolute_package_dir(args)?;
+    let out = get_out(args);
+
+    Ok((absolute_package_dir, out))
+}
+
+fn get_absolute_package_dir(args: &[String]) -> Result<String, String> {
+    let absolute_package_dir = args.iter().position(|arg| arg == "--absolute-package-dir");
+    let absolute_package_dir = match absolute_package_dir {
+        Some(index) => args.get(index + 1),
+        None => None,
+    };
+
+    match absolute_package_dir {
+        Some(value) => Ok(value.to_string()),
+        None => {
+            Err("--absolute-package-dir not found; use --absolute-package-dir <path>".to_owned())
+        }
+    }
+}
+
+fn get_out(args: &[String]) -> Option<String> {
+    let out = args.iter().position(|arg| arg == "--out");
+    match out {
+        Some(index) => args.get(index + 1).map(|value| value.to_string()),
+        None => None,
+    }
+}
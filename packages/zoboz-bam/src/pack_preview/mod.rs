@@ -0,0 +1,106 @@
+mod cli_flags;
+mod tarball;
+
+use std::fs;
+
+use cli_flags::get_params;
+use tarball::{create_tarball, normalize_mode, TarballEntry};
+
+use crate::shared::{
+    npm_packlist::list_packed_files,
+    package_json_reader::{get_package_json_object, get_package_json_string},
+    value_objects::AbsolutePackageDir,
+};
+
+pub fn run_by_args(args: &[String]) -> Result<(), String> {
+    let (absolute_package_dir, out) = get_params(args)?;
+
+    run_by_params(&absolute_package_dir, out.as_deref())
+}
+
+pub fn run_by_params(absolute_package_dir: &str, out: Option<&str>) -> Result<(), String> {
+    let package_dir = AbsolutePackageDir::new(absolute_package_dir)?;
+
+    let package_json_content = get_package_json_string(&package_dir);
+    if package_json_content.is_empty() {
+        return Err(format!(
+            "No package.json found in '{}'",
+            package_dir.value().to_string_lossy()
+        ));
+    }
+
+    let package_json = get_package_json_object(&package_json_content);
+    let mut packed_files = list_packed_files(&package_dir, &package_json);
+    packed_files.sort_by_key(|relative_path| get_pack_order_key(relative_path));
+
+    let entries = packed_files
+        .into_iter()
+        .map(|relative_path| {
+            let file_path = package_dir.value().join(&relative_path);
+            let content = fs::read(&file_path)
+                .map_err(|e| format!("Failed to read '{}': {}", relative_path, e))?;
+
+            Ok(TarballEntry {
+                mode: normalize_mode(get_mode(&file_path)),
+                path: relative_path,
+                content,
+            })
+        })
+        .collect::<Result<Vec<_>, String>>()?;
+
+    let size_width = entries
+        .iter()
+        .map(|entry| entry.content.len().to_string().len())
+        .max()
+        .unwrap_or_default();
+
+    for entry in &entries {
+        println!(
+            "{:>width$} B  {}",
+            entry.content.len(),
+            entry.path,
+            width = size_width
+        );
+    }
+
+    let unpacked_size: usize = entries.iter().map(|entry| entry.content.len()).sum();
+    println!("total files: {}", entries.len());
+    println!("unpacked size: {} B", unpacked_size);
+
+    if let Some(out) = out {
+        let tarball = create_tarball(&entries)?;
+        fs::write(out, &tarball).map_err(|e| format!("Failed to write '{}': {}", out, e))?;
+        println!("packed size: {} B", tarball.len());
+    }
+
+    Ok(())
+}
+
+/// npm-packlist groups files by extension, then by file name, to help compression.
+fn get_pack_order_key(relative_path: &str) -> (String, String, String) {
+    let path = std::path::Path::new(relative_path);
+    let file_name = path
+        .file_name()
+        .map(|file_name| file_name.to_string_lossy().to_lowercase())
+        .unwrap_or_default();
+    let extension = path
+        .extension()
+        .map(|extension| format!(".{}", extension.to_string_lossy().to_lowercase()))
+        .unwrap_or_default();
+
+    (extension, file_name, relative_path.to_lowercase())
+}
+
+#[cfg(unix)]
+fn get_mode(file_path: &std::path::Path) -> u32 {
+    use std::os::unix::fs::PermissionsExt;
+
+    fs::metadata(file_path)
+        .map(|metadata| metadata.permissions().mode())
+        .unwrap_or(0o644)
+}
+
+#[cfg(not(unix))]
+fn get_mode(_file_path: &std::path::Path) -> u32 {
+    0o644
+}
@@ -0,0 +1,101 @@
+use std::io::Write;
+
+use flate2::{write::GzEncoder, Compression, GzBuilder};
+
+// npm stamps every entry with this date, zip tools get confused by files dated at epoch 0.
+const NPM_MTIME: u64 = 499162500;
+const BLOCK_SIZE: usize = 512;
+
+pub(super) struct TarballEntry {
+    pub path: String,
+    pub mode: u32,
+    pub content: Vec<u8>,
+}
+
+/// Writes the entries the way npm does through node-tar in portable mode: a `package/` prefix,
+/// no directory entries, no owner metadata and a fixed mtime. The content matches npm's, but the
+/// bytes don't, so neither do the shasum and integrity.
+pub(super) fn create_tarball(entries: &[TarballEntry]) -> Result<Vec<u8>, String> {
+    let mut tar: Vec<u8> = vec![];
+
+    for entry in entries {
+        tar.extend(create_header(entry)?);
+        tar.extend(&entry.content);
+        tar.extend(vec![0; padding(entry.content.len())]);
+    }
+
+    tar.extend(vec![0; BLOCK_SIZE * 2]);
+
+    // Level 9 through zlib like npm, whose gzip header leaves the OS unknown.
+    let mut encoder: GzEncoder<Vec<u8>> = GzBuilder::new()
+        .operating_system(255)
+        .write(vec![], Compression::best());
+    encoder
+        .write_all(&tar)
+        .map_err(|e| format!("Failed to compress tarball: {}", e))?;
+    encoder
+        .finish()
+        .map_err(|e| format!("Failed to compress tarball: {}", e))
+}
+
+/// Applies node-tar's portable umask; npm only marks `bin` files executable on install.
+pub(super) fn normalize_mode(mode: u32) -> u32 {
+    ((mode & 0o7777) | 0o600) & !0o022
+}
+
+fn create_header(entry: &TarballEntry) -> Result<[u8; BLOCK_SIZE], String> {
+    let mut header = [0u8; BLOCK_SIZE];
+    let path = format!("package/{}", entry.path);
+    let (prefix, name) = split_path(&path)?;
+
+    header[0..name.len()].copy_from_slice(name.as_bytes());
+    write_octal(&mut header[100..108], entry.mode as u64);
+    write_octal(&mut header[124..136], entry.content.len() as u64);
+    write_octal(&mut header[136..148], NPM_MTIME);
+    header[156] = b'0';
+    header[257..263].copy_from_slice(b"ustar\0");
+    header[263..265].copy_from_slice(b"00");
+    write_octal(&mut header[329..337], 0);
+    write_octal(&mut header[337..345], 0);
+    header[345..345 + prefix.len()].copy_from_slice(prefix.as_bytes());
+
+    // The checksum is calculated as if its own field was filled with spaces.
+    let checksum: u64 = header[..148]
+        .iter()
+        .chain(header[156..].iter())
+        .map(|byte| *byte as u64)
+        .sum::<u64>()
+        + 8 * 0x20;
+    write_octal(&mut header[148..156], checksum);
+
+    Ok(header)
+}
+
+/// Paths over 100 bytes get split into ustar's 155 byte prefix and 100 byte name.
+fn split_path(path: &str) -> Result<(&str, &str), String> {
+    if path.len() <= 100 {
+        return Ok(("", path));
+    }
+
+    path.char_indices()
+        .filter(|(_, c)| *c == '/')
+        .map(|(index, _)| (&path[..index], &path[index + 1..]))
+        .find(|(prefix, name)| prefix.len() <= 155 && name.len() <= 100)
+        .ok_or_else(|| format!("Path '{}' is too long to be stored in a tarball", path))
+}
+
+/// Mirrors node-tar: zero padded octal digits followed by a space and a NUL.
+fn write_octal(field: &mut [u8], value: u64) {
+    let digits = format!("{:o}", value);
+    let text = if digits.len() == field.len() - 1 {
+        format!("{}\0", digits)
+    } else {
+        format!("{:0>width$} \0", digits, width = field.len() - 2)
+    };
+
+    field.copy_from_slice(text.as_bytes());
+}
+
+fn padding(size: usize) -> usize {
+    (BLOCK_SIZE - size % BLOCK_SIZE) % BLOCK_SIZE
+}
@@ -0,0 +1,154 @@
+use std::fs;
+use std::path::Path;
+
+use crate::shared::json_editor::ChangeSet;
+use crate::shared::module_format::{
+    detect_explicit_syntax_format, detect_syntax_format, get_nearest_type_field, ModuleFormat,
+};
+use crate::shared::package_json_reader::PackageJson;
+use crate::shared::utils;
+use crate::shared::value_objects::AbsolutePackageDir;
+
+use super::diagnostic::{Rule, Severity};
+
+pub(crate) const RULE: Rule = Rule {
+    id: "bin-entries-validity",
+    severity: Severity::Error,
+    options: &[],
+};
+
+const SHEBANG: &str = "#!/usr/bin/env node";
+
+/// Shebangs and executable bits live in the bin files rather than package.json, so in fix-mode
+/// they are fixed right here and only what is left gets reported.
+pub(crate) fn validate(
+    package_dir: &AbsolutePackageDir,
+    package_json: &PackageJson,
+    can_update_files: bool,
+    change_sets: &mut Vec<ChangeSet>,
+) {
+    for (command_name, bin_path) in package_json.get_bin_entries() {
+        let json_path = package_json.get_bin_json_path(&command_name);
+        let mut problems: Vec<String> = vec![];
+
+        let Some(relative_path) = utils::normalize_relative_path(&bin_path) else {
+            problems.push(format!(
+                "Bin `{}` points to `{}`, which is outside the package.",
+                command_name, bin_path
+            ));
+            push_unfixable(problems, &json_path, change_sets);
+            continue;
+        };
+
+        let file_path = package_dir.value().join(&relative_path);
+        let Ok(mut content) = fs::read_to_string(&file_path) else {
+            problems.push(format!(
+                "Bin `{}` points to `{}`, which does not exist.",
+                command_name, bin_path
+            ));
+            push_unfixable(problems, &json_path, change_sets);
+            continue;
+        };
+
+        if !content.starts_with("#!") {
+            if can_update_files {
+                content = format!("{}\n{}", SHEBANG, content);
+                if let Err(e) = fs::write(&file_path, &content) {
+                    problems.push(format!("Failed to add a shebang to `{}`: {}", bin_path, e));
+                }
+            } else {
+                problems.push(format!(
+                    "Bin `{}` file `{}` does not start with `{}`.",
+                    command_name, bin_path, SHEBANG
+                ));
+            }
+        }
+
+        if !is_executable(&file_path) {
+            if can_update_files {
+                if let Err(e) = make_executable(&file_path) {
+                    problems.push(format!("Failed to make `{}` executable: {}", bin_path, e));
+                }
+            } else {
+                problems.push(format!(
+                    "Bin `{}` file `{}` is not executable.",
+                    command_name, bin_path
+                ));
+            }
+        }
+
+        match get_node_format(&file_path) {
+            ModuleFormat::Cjs if detect_syntax_format(&content) == ModuleFormat::Esm => {
+                problems.push(format!(
+                    "Bin `{}` file `{}` is written as ESM but Node.js will run it as CJS; give it the `.mjs` extension.",
+                    command_name, bin_path
+                ));
+            }
+            ModuleFormat::Esm
+                if detect_explicit_syntax_format(&content) == Some(ModuleFormat::Cjs) =>
+            {
+                problems.push(format!(
+                    "Bin `{}` file `{}` is written as CJS but Node.js will run it as ESM; give it the `.cjs` extension.",
+                    command_name, bin_path
+                ));
+            }
+            _ => {}
+        }
+
+        push_unfixable(problems, &json_path, change_sets);
+    }
+}
+
+fn push_unfixable(problems: Vec<String>, json_path: &str, change_sets: &mut Vec<ChangeSet>) {
+    for problem in problems {
+        change_sets.push(ChangeSet {
+            description: problem,
+            json_path: json_path.to_string(),
+            changes: vec![],
+        });
+    }
+}
+
+/// Unlike imports, running a file never sniffs its syntax: the extension wins, then `type`.
+fn get_node_format(file_path: &Path) -> ModuleFormat {
+    match file_path
+        .extension()
+        .and_then(|extension| extension.to_str())
+    {
+        Some("mjs") => ModuleFormat::Esm,
+        Some("cjs") => ModuleFormat::Cjs,
+        _ => match get_nearest_type_field(file_path).as_deref() {
+            Some("module") => ModuleFormat::Esm,
+            _ => ModuleFormat::Cjs,
+        },
+    }
+}
+
+#[cfg(unix)]
+fn is_executable(file_path: &Path) -> bool {
+    use std::os::unix::fs::PermissionsExt;
+
+    fs::metadata(file_path)
+        .map(|metadata| metadata.permissions().mode() & 0o111 != 0)
+        .unwrap_or(false)
+}
+
+#[cfg(unix)]
+fn make_executable(file_path: &Path) -> std::io::Result<()> {
+    use std::os::unix::fs::PermissionsExt;
+
+    let mut permissions = fs::metadata(file_path)?.permissions();
+    permissions.set_mode(permissions.mode() | 0o111);
+    fs::set_permissions(file_path, permissions)
+}
+
+// Windows has no executable bit, npm creates shims for bins there instead.
+#[cfg(not(unix))]
+fn is_executable(_file_path: &Path) -> bool {
+    true
+}
+
+#[cfg(not(unix))]
+fn make_executable(_file_path: &Path) -> std::io::Result<()> {
+    Ok(())
+}
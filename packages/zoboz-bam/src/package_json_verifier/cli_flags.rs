@@ -1,9 +1,74 @@
-pub(super) fn get_params(args: &[String]) -> Result<(String, bool), String> {
-    let absolute_package_dir = get_absolute_package_dir(&args)?;
+/// Either a single package, or every package of a workspace with output directories relative to each.
+pub(super) enum Target {
+    Package {
+        absolute_package_dir: String,
+        absolute_output_dirs: Vec<String>,
+    },
+    Workspace {
+        workspace_root: String,
+        relative_output_dirs: Vec<String>,
+    },
+}
 
-    let can_update_package_json = get_can_update_package_json(args);
+pub(super) struct Params {
+    pub target: Target,
+    pub can_update_package_json: bool,
+    pub shows_diff: bool,
+    pub prefers_peer_dependencies: bool,
+    pub key_order: String,
+    pub format: String,
+    pub reporter: Option<String>,
+    pub report_file: Option<String>,
+}
 
-    Ok((absolute_package_dir, can_update_package_json))
+pub(super) fn get_params(args: &[String]) -> Result<Params, String> {
+    Ok(Params {
+        target: get_target(args)?,
+        can_update_package_json: get_can_update_package_json(args),
+        shows_diff: get_shows_diff(args),
+        prefers_peer_dependencies: get_prefers_peer_dependencies(args),
+        key_order: get_key_order(args),
+        format: get_format(args),
+        reporter: get_reporter(args),
+        report_file: get_report_file(args),
+    })
+}
+
+fn get_target(args: &[String]) -> Result<Target, String> {
+    let Some(workspace_root) = get_workspace_root(args) else {
+        return Ok(Target::Package {
+            absolute_package_dir: get_absolute_package_dir(args)?,
+            absolute_output_dirs: get_absolute_output_dirs(args),
+        });
+    };
+
+    if args
+        .iter()
+        .any(|arg| arg == "--absolute-package-dir" || arg == "--absolute-output-dir")
+    {
+        return Err("--workspace-root can't be combined with --absolute-package-dir or --absolute-output-dir; use --relative-output-dir <path>".to_owned());
+    }
+
+    Ok(Target::Workspace {
+        workspace_root,
+        relative_output_dirs: get_relative_output_dirs(args),
+    })
+}
+
+fn get_workspace_root(args: &[String]) -> Option<String> {
+    args.iter()
+        .position(|arg| arg == "--workspace-root")
+        .and_then(|index| args.get(index + 1))
+        .map(|value| value.to_string())
+}
+
+fn get_relative_output_dirs(args: &[String]) -> Vec<String> {
+    args.iter()
+        .enumerate()
+        .filter(|(_, arg)| *arg == "--relative-output-dir")
+        .filter_map(|(index, _)| args.get(index + 1))
+        .map(|value| value.to_string())
+        .collect()
 }
 
 fn get_absolute_package_dir(args: &[String]) -> Result<String, String> {
@@ -21,6 +86,51 @@ fn get_absolute_package_dir(args: &[String]) -> Result<String, String> {
     }
 }
 
+fn get_absolute_output_dirs(args: &[String]) -> Vec<String> {
+    args.iter()
+        .enumerate()
+        .filter(|(_, arg)| *arg == "--absolute-output-dir")
+        .filter_map(|(index, _)| args.get(index + 1))
+        .map(|value| value.to_string())
+        .collect()
+}
+
 fn get_can_update_package_json(args: &[String]) -> bool {
     args.iter().any(|arg| arg == "--can-update-package-json")
 }
+
+fn get_shows_diff(args: &[String]) -> bool {
+    args.iter().any(|arg| arg == "--diff")
+}
+
+fn get_prefers_peer_dependencies(args: &[String]) -> bool {
+    args.iter().any(|arg| arg == "--prefer-peer-dependencies")
+}
+
+fn get_key_order(args: &[String]) -> String {
+    args.iter()
+        .position(|arg| arg == "--key-order")
+        .and_then(|index| args.get(index + 1))
+        .map_or("append".to_string(), |value| value.to_string())
+}
+
+fn get_format(args: &[String]) -> String {
+    args.iter()
+        .position(|arg| arg == "--format")
+        .and_then(|index| args.get(index + 1))
+        .map_or("text".to_string(), |value| value.to_string())
+}
+
+fn get_reporter(args: &[String]) -> Option<String> {
+    args.iter()
+        .position(|arg| arg == "--reporter")
+        .and_then(|index| args.get(index + 1))
+        .map(|value| value.to_string())
+}
+
+fn get_report_file(args: &[String]) -> Option<String> {
+    args.iter()
+        .position(|arg| arg == "--report-file")
+        .and_then(|index| args.get(index + 1))
+        .map(|value| value.to_string())
+}
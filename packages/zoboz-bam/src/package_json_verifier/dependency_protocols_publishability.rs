@@ -0,0 +1,134 @@
+use std::collections::HashMap;
+
+use serde_json::Value;
+
+use crate::shared::json_editor::{to_json_pointer, Change, ChangeSet, ChangeType};
+use crate::shared::package_json_reader::{get_package_json, PackageJson};
+use crate::shared::value_objects::AbsolutePackageDir;
+use crate::shared::workspaces::{find_workspace_root, get_workspace_package_paths};
+
+use super::diagnostic::{Rule, Severity};
+
+pub(crate) const RULE: Rule = Rule {
+    id: "dependency-protocols-publishability",
+    severity: Severity::Error,
+    options: &[],
+};
+
+pub(crate) fn validate(
+    package_dir: &AbsolutePackageDir,
+    package_json: &PackageJson,
+    change_sets: &mut Vec<ChangeSet>,
+) {
+    // Only read the whole workspace once a `workspace:` dependency asks for it.
+    let mut workspace_versions: Option<HashMap<String, String>> = None;
+
+    for (field, dependencies) in [
+        ("dependencies", &package_json.dependencies),
+        ("peerDependencies", &package_json.peer_dependencies),
+    ] {
+        let mut names: Vec<&String> = dependencies.keys().collect();
+        names.sort();
+
+        for name in names {
+            let specifier = &dependencies[name];
+
+            let range = if let Some(workspace_range) = specifier.strip_prefix("workspace:") {
+                let workspace_versions =
+                    workspace_versions.get_or_insert_with(|| get_workspace_versions(package_dir));
+                get_workspace_range(name, workspace_range, workspace_versions)
+            } else if let Some(path) = specifier
+                .strip_prefix("file:")
+                .or_else(|| specifier.strip_prefix("link:"))
+            {
+                get_path_range(package_dir, path)
+            } else {
+                continue;
+            };
+
+            let (hint, changes) = match range {
+                Some(range) => (
+                    format!("Use `{}` instead.", range),
+                    vec![Change {
+                        path: to_json_pointer(&[field, name]),
+                        change_type: ChangeType::Update,
+                        value: Some(Value::String(range)),
+                    }],
+                ),
+                None => (
+                    "No sibling package provides its version, so it has to be replaced by hand."
+                        .to_string(),
+                    vec![],
+                ),
+            };
+
+            change_sets.push(ChangeSet {
+                description: format!(
+                    "`{}` in `{}` is `{}`, which only resolves inside this repository, so consumers can't install it. {}",
+                    name, field, specifier, hint
+                ),
+                json_path: to_json_pointer(&[field, name]),
+                changes,
+            });
+        }
+    }
+}
+
+/// Resolves `workspace:` the way `pnpm publish` does: `*` pins the sibling's version, `^` and `~`
+/// prefix it, and anything else is already a range. `workspace:other@*` depends on an alias.
+fn get_workspace_range(
+    name: &str,
+    workspace_range: &str,
+    workspace_versions: &HashMap<String, String>,
+) -> Option<String> {
+    let (alias, workspace_range) = match workspace_range.rfind('@') {
+        Some(index) if index > 0 => (
+            Some(&workspace_range[..index]),
+            &workspace_range[index + 1..],
+        ),
+        _ => (None, workspace_range),
+    };
+
+    let range = match workspace_range {
+        "" | "*" | "^" | "~" => {
+            let version = workspace_versions.get(alias.unwrap_or(name))?;
+            format!("{}{}", workspace_range.trim_start_matches('*'), version)
+        }
+        range => range.to_string(),
+    };
+
+    Some(match alias {
+        Some(alias) => format!("npm:{}@{}", alias, range),
+        None => range,
+    })
+}
+
+/// `file:` and `link:` point at a directory whose version is what consumers should get instead.
+fn get_path_range(package_dir: &AbsolutePackageDir, path: &str) -> Option<String> {
+    let sibling_dir = package_dir.value().join(path);
+    if !sibling_dir.join("package.json").is_file() {
+        return None;
+    }
+
+    let sibling_dir = AbsolutePackageDir::new(&sibling_dir.to_string_lossy()).ok()?;
+    get_package_json(&sibling_dir)
+        .version
+        .map(|version| format!("^{}", version))
+}
+
+fn get_workspace_versions(package_dir: &AbsolutePackageDir) -> HashMap<String, String> {
+    let Some(workspace_root) = find_workspace_root(package_dir) else {
+        return HashMap::new();
+    };
+
+    get_workspace_package_paths(&workspace_root)
+        .unwrap_or_default()
+        .iter()
+        .filter_map(|package_path| {
+            AbsolutePackageDir::new(&workspace_root.value().join(package_path).to_string_lossy())
+                .ok()
+        })
+        .map(|sibling_dir| get_package_json(&sibling_dir))
+        .filter_map(|sibling| Some((sibling.name?, sibling.version?)))
+        .collect()
+}
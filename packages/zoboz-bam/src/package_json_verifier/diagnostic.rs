@@ -0,0 +1,145 @@
+use serde_json::{json, Value};
+
+use crate::shared::json_editor::ChangeSet;
+use crate::shared::reporter::Level;
+
+#[derive(Clone, Copy, PartialEq, Eq)]
+pub(crate) enum Severity {
+    Error,
+    Warn,
+    Info,
+}
+
+impl Severity {
+    pub(crate) fn new(severity: &str) -> Option<Self> {
+        match severity {
+            "error" => Some(Severity::Error),
+            "warn" => Some(Severity::Warn),
+            "info" => Some(Severity::Info),
+            _ => None,
+        }
+    }
+
+    pub(crate) fn to_level(self) -> Level {
+        match self {
+            Severity::Error => Level::Error,
+            Severity::Warn => Level::Warning,
+            Severity::Info => Level::Note,
+        }
+    }
+
+    pub(crate) fn as_str(&self) -> &'static str {
+        match self {
+            Severity::Error => "error",
+            Severity::Warn => "warn",
+            Severity::Info => "info",
+        }
+    }
+}
+
+/// A verifier rule; its ID is part of the output and the configuration, so it must never change
+/// once released. Every option is a list of strings.
+#[derive(Clone, Copy)]
+pub(crate) struct Rule {
+    pub id: &'static str,
+    pub severity: Severity,
+    pub options: &'static [&'static str],
+}
+
+pub(crate) struct Diagnostic {
+    pub rule: Rule,
+    pub change_set: ChangeSet,
+}
+
+impl Diagnostic {
+    pub(crate) fn is_fixable(&self) -> bool {
+        !self.change_set.changes.is_empty()
+    }
+
+    fn to_text(&self) -> String {
+        match self.rule.severity {
+            Severity::Error => self.change_set.description.clone(),
+            severity => format!("{}: {}", severity.as_str(), self.change_set.description),
+        }
+    }
+
+    fn to_json(&self) -> Value {
+        json!({
+            "ruleId": self.rule.id,
+            "severity": self.rule.severity.as_str(),
+            "jsonPath": self.change_set.json_path,
+            "message": self.change_set.description,
+            "fixable": self.is_fixable(),
+        })
+    }
+}
+
+#[derive(Clone, Copy, PartialEq, Eq)]
+pub(crate) enum OutputFormat {
+    Text,
+    Json,
+}
+
+impl OutputFormat {
+    pub(crate) fn new(format: &str) -> Result<Self, String> {
+        match format {
+            "text" => Ok(OutputFormat::Text),
+            "json" => Ok(OutputFormat::Json),
+            _ => Err(format!("Invalid format '{}'; use `text` or `json`", format)),
+        }
+    }
+
+    pub(crate) fn render(&self, diagnostics: &[Diagnostic]) -> String {
+        match self {
+            OutputFormat::Text => diagnostics
+                .iter()
+                .map(|diagnostic| diagnostic.to_text())
+                .collect::<Vec<_>>()
+                .join("\n"),
+            OutputFormat::Json => serde_json::to_string_pretty(&Value::Array(
+                diagnostics
+                    .iter()
+                    .map(|diagnostic| diagnostic.to_json())
+                    .collect(),
+            ))
+            .unwrap(),
+        }
+    }
+
+    /// Renders the diagnostics of every workspace package, grouped under its path. Text output
+    /// leaves out the packages without any diagnostics, JSON output is an object keyed by path.
+    pub(crate) fn render_by_package(&self, packages: &[(&str, &[Diagnostic])]) -> String {
+        match self {
+            OutputFormat::Text => packages
+                .iter()
+                .filter(|(_, diagnostics)| !diagnostics.is_empty())
+                .map(|(package_path, diagnostics)| {
+                    let lines = diagnostics
+                        .iter()
+                        .map(|diagnostic| format!("  {}", diagnostic.to_text()))
+                        .collect::<Vec<_>>()
+                        .join("\n");
+                    format!("{}\n{}", package_path, lines)
+                })
+                .collect::<Vec<_>>()
+                .join("\n\n"),
+            OutputFormat::Json => serde_json::to_string_pretty(&Value::Object(
+                packages
+                    .iter()
+                    .map(|(package_path, diagnostics)| {
+                        (
+                            package_path.to_string(),
+                            Value::Array(
+                                diagnostics
+                                    .iter()
+                                    .map(|diagnostic| diagnostic.to_json())
+                                    .collect(),
+                            ),
+                        )
+                    })
+                    .collect(),
+            ))
+            .unwrap(),
+        }
+    }
+}
@@ -0,0 +1,162 @@
+use serde_json::{Map, Value};
+
+use crate::shared::json_editor::{to_json_pointer, Change, ChangeSet, ChangeType};
+use crate::shared::package_json_reader::PackageJson;
+
+use super::diagnostic::{Rule, Severity};
+
+pub(crate) const RULE: Rule = Rule {
+    id: "exports-conditions-order",
+    severity: Severity::Error,
+    options: &[],
+};
+
+// Conditions every resolver understands; anything else is a custom condition.
+const GENERIC_CONDITIONS: &[&str] = &["node", "module-sync", "import", "require"];
+
+pub(crate) fn validate(package_json: &PackageJson, change_sets: &mut Vec<ChangeSet>) {
+    let Some(exports) = &package_json.exports else {
+        return;
+    };
+
+    let mut misordered: Vec<Misordered> = vec![];
+    collect_misordered(
+        exports,
+        "exports".to_string(),
+        vec!["exports".to_string()],
+        &mut misordered,
+    );
+
+    for Misordered {
+        json_path,
+        keys,
+        actual,
+        expected,
+        reordered,
+    } in misordered
+    {
+        let keys: Vec<&str> = keys.iter().map(|key| key.as_str()).collect();
+
+        change_sets.push(ChangeSet {
+            description: format!(
+                "Conditions of `{}` should be ordered as `{}` instead of `{}`; `types` must come first, custom conditions before the generic ones, and `default` last.",
+                json_path,
+                expected.join("`, `"),
+                actual.join("`, `"),
+            ),
+            json_path: to_json_pointer(&keys),
+            changes: vec![Change {
+                path: to_json_pointer(&keys),
+                change_type: ChangeType::Update,
+                value: Some(reordered),
+            }],
+        });
+    }
+}
+
+struct Misordered {
+    json_path: String,
+    keys: Vec<String>,
+    actual: Vec<String>,
+    expected: Vec<String>,
+    reordered: Value,
+}
+
+/// Outer objects come before the ones nested in them, so their updates never undo the inner fixes.
+fn collect_misordered(
+    value: &Value,
+    json_path: String,
+    keys: Vec<String>,
+    misordered: &mut Vec<Misordered>,
+) {
+    let with_key = |key: String| {
+        let mut keys = keys.clone();
+        keys.push(key);
+        keys
+    };
+
+    match value {
+        Value::Array(items) => {
+            for (index, item) in items.iter().enumerate() {
+                collect_misordered(
+                    item,
+                    format!("{}[{}]", json_path, index),
+                    with_key(index.to_string()),
+                    misordered,
+                );
+            }
+        }
+        Value::Object(entries) => {
+            if is_conditions_object(entries) {
+                let actual: Vec<String> = entries.keys().cloned().collect();
+                let expected: Vec<String> = sort_conditions(entries).keys().cloned().collect();
+
+                if actual != expected {
+                    misordered.push(Misordered {
+                        json_path: json_path.clone(),
+                        keys: keys.clone(),
+                        actual,
+                        expected,
+                        reordered: reorder(value),
+                    });
+                }
+            }
+
+            for (key, item) in entries {
+                collect_misordered(
+                    item,
+                    format!("{}[\"{}\"]", json_path, key),
+                    with_key(key.clone()),
+                    misordered,
+                );
+            }
+        }
+        _ => {}
+    }
+}
+
+fn reorder(value: &Value) -> Value {
+    match value {
+        Value::Array(items) => Value::Array(items.iter().map(reorder).collect()),
+        Value::Object(entries) => {
+            let entries = if is_conditions_object(entries) {
+                sort_conditions(entries)
+            } else {
+                entries.clone()
+            };
+
+            Value::Object(
+                entries
+                    .iter()
+                    .map(|(key, item)| (key.clone(), reorder(item)))
+                    .collect(),
+            )
+        }
+        _ => value.clone(),
+    }
+}
+
+/// Subpath objects have keys starting with `.`, condition objects never do.
+fn is_conditions_object(entries: &Map<String, Value>) -> bool {
+    entries.keys().all(|key| !key.starts_with('.'))
+}
+
+fn sort_conditions(entries: &Map<String, Value>) -> Map<String, Value> {
+    let mut sorted: Vec<(&String, &Value)> = entries.iter().collect();
+    // A stable sort keeps the relative order of conditions sharing a rank.
+    sorted.sort_by_key(|(key, _)| get_rank(key));
+
+    sorted
+        .into_iter()
+        .map(|(key, item)| (key.clone(), item.clone()))
+        .collect()
+}
+
+fn get_rank(condition: &str) -> u8 {
+    match condition {
+        "types" => 0,
+        "default" => 3,
+        _ if GENERIC_CONDITIONS.contains(&condition) => 2,
+        _ => 1,
+    }
+}
@@ -0,0 +1,138 @@
+use std::path::{Component, Path};
+
+use serde_json::Value;
+
+use crate::shared::json_editor::{to_json_pointer, ChangeSet};
+use crate::shared::package_json_reader::PackageJson;
+use crate::shared::utils;
+use crate::shared::value_objects::AbsolutePackageDir;
+
+use super::diagnostic::{Rule, Severity};
+
+pub(crate) const RULE: Rule = Rule {
+    id: "exports-targets-existence",
+    severity: Severity::Error,
+    options: &[],
+};
+
+pub(crate) fn validate(
+    package_dir: &AbsolutePackageDir,
+    package_json: &PackageJson,
+    change_sets: &mut Vec<ChangeSet>,
+) {
+    let Some(exports) = &package_json.exports else {
+        return;
+    };
+
+    let mut targets: Vec<(String, String, String)> = vec![];
+    collect_targets(
+        exports,
+        "exports".to_string(),
+        vec!["exports".to_string()],
+        &mut targets,
+    );
+
+    let package_files = utils::list_relative_files(package_dir.value(), &["node_modules", ".git"]);
+
+    for (json_path, json_pointer, target) in targets {
+        let problem = if !target.starts_with("./") {
+            "is missing its leading `./`"
+        } else if is_outside_package(&target) {
+            "points outside the package"
+        } else if !matches_any_file(package_dir.value(), &package_files, &target) {
+            "matches no file"
+        } else {
+            continue;
+        };
+
+        change_sets.push(ChangeSet {
+            description: format!("Target `{}` of `{}` {}.", target, json_path, problem),
+            json_path: json_pointer,
+            changes: vec![],
+        });
+    }
+}
+
+/// Walks subpaths, condition objects and fallback arrays; `null` exclusions have no target.
+/// Each target comes with its display path and its JSON Pointer.
+pub(super) fn collect_targets(
+    value: &Value,
+    json_path: String,
+    keys: Vec<String>,
+    targets: &mut Vec<(String, String, String)>,
+) {
+    match value {
+        Value::String(target) => {
+            let keys: Vec<&str> = keys.iter().map(|key| key.as_str()).collect();
+            targets.push((json_path, to_json_pointer(&keys), target.clone()));
+        }
+        Value::Array(items) => {
+            for (index, item) in items.iter().enumerate() {
+                collect_targets(
+                    item,
+                    format!("{}[{}]", json_path, index),
+                    [keys.clone(), vec![index.to_string()]].concat(),
+                    targets,
+                );
+            }
+        }
+        Value::Object(entries) => {
+            for (key, item) in entries {
+                collect_targets(
+                    item,
+                    format!("{}[\"{}\"]", json_path, key),
+                    [keys.clone(), vec![key.clone()]].concat(),
+                    targets,
+                );
+            }
+        }
+        _ => {}
+    }
+}
+
+fn is_outside_package(target: &str) -> bool {
+    let mut depth = 0;
+
+    for component in Path::new(target).components() {
+        match component {
+            Component::ParentDir => depth -= 1,
+            Component::Normal(_) => depth += 1,
+            _ => {}
+        }
+
+        if depth < 0 {
+            return true;
+        }
+    }
+
+    false
+}
+
+fn matches_any_file(package_dir: &Path, package_files: &[String], target: &str) -> bool {
+    if !target.contains('*') {
+        let target_path = package_dir.join(target);
+        return if target.ends_with('/') {
+            target_path.is_dir()
+        } else {
+            target_path.is_file()
+        };
+    }
+
+    let pattern = get_target_pattern(target);
+
+    package_files
+        .iter()
+        .any(|package_file| pattern.is_match(package_file))
+}
+
+/// Like Node.js, every `*` stands for any string, including ones containing `/`.
+pub(super) fn get_target_pattern(target: &str) -> regex::Regex {
+    let pattern = target
+        .trim_start_matches("./")
+        .split('*')
+        .map(regex::escape)
+        .collect::<Vec<_>>()
+        .join(".+");
+
+    regex::Regex::new(&format!("^{}$", pattern)).unwrap()
+}
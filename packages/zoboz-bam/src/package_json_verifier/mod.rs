@@ -1,55 +1,405 @@
+mod bin_entries_validity;
 mod cli_flags;
+mod dependency_protocols_publishability;
+mod diagnostic;
+mod exports_conditions_order;
+mod exports_targets_existence;
+mod output_type_markers;
+mod peer_dependencies_consistency;
+mod published_files_inclusion;
+mod rules_config;
+mod runtime_dependencies_availability;
+mod side_effects_declaration;
 mod type_field_remover;
 
 use std::{fs, string::String};
 
-use cli_flags::get_params;
+use cli_flags::{get_params, Params, Target};
+use diagnostic::{Diagnostic, OutputFormat, Rule, Severity};
+use rules_config::RulesConfig;
 
 use crate::shared::{
-    json_editor::{apply_change_sets, ChangeSet},
+    json_editor::{apply_change_sets, parse_json_pointer, ChangeSet, KeyOrder},
+    json_spans,
     package_json_reader::{get_package_json_object, get_package_json_string},
-    value_objects::AbsolutePackageDir,
+    reporter::{get_line_and_column, Finding, Reporter},
+    unified_diff::get_unified_diff,
+    value_objects::{AbsoluteOutputDir, AbsolutePackageDir},
+    workspaces::get_workspace_package_paths,
 };
 
+const RULES: &[Rule] = &[
+    type_field_remover::RULE,
+    runtime_dependencies_availability::RULE,
+    peer_dependencies_consistency::RULE,
+    peer_dependencies_consistency::META_RULE,
+    dependency_protocols_publishability::RULE,
+    exports_targets_existence::RULE,
+    exports_conditions_order::RULE,
+    published_files_inclusion::RULE,
+    side_effects_declaration::RULE,
+    side_effects_declaration::TREE_SHAKING_RULE,
+    bin_entries_validity::RULE,
+    output_type_markers::RULE,
+];
+
 pub fn run_by_args(args: &[String]) -> Result<(), String> {
-    let (absolute_package_dir, can_update_package_json) = get_params(args)?;
+    let params = get_params(args)?;
 
-    run_by_params(&absolute_package_dir, can_update_package_json)
+    match &params.target {
+        Target::Package {
+            absolute_package_dir,
+            absolute_output_dirs,
+        } => run_by_params(absolute_package_dir, absolute_output_dirs, &params),
+        Target::Workspace {
+            workspace_root,
+            relative_output_dirs,
+        } => run_workspace_by_params(workspace_root, relative_output_dirs, &params),
+    }
 }
 
-pub fn run_by_params(
+/// Verifies a single package, with the options of `params`; its target is already taken apart.
+fn run_by_params(
     absolute_package_dir: &str,
-    can_update_package_json: bool,
+    absolute_output_dirs: &[String],
+    params: &Params,
 ) -> Result<(), String> {
     let absolute_package_dir = AbsolutePackageDir::new(absolute_package_dir)?;
+    let key_order = KeyOrder::new(&params.key_order)?;
+    let format = OutputFormat::new(&params.format)?;
+    let reporter = params.reporter.as_deref().map(Reporter::new).transpose()?;
+    let report_file = params.report_file.as_deref();
+    let console_reporter = Reporter::for_console(reporter, report_file);
+    let absolute_output_dirs = absolute_output_dirs
+        .iter()
+        .map(|absolute_output_dir| AbsoluteOutputDir::new(absolute_output_dir))
+        .collect::<Result<Vec<_>, _>>()?;
 
-    let mut package_json_content = get_package_json_string(&absolute_package_dir);
-    let package_json = get_package_json_object(&package_json_content);
+    let verification = verify_package(
+        &absolute_package_dir,
+        &absolute_output_dirs,
+        params.can_update_package_json,
+        params.prefers_peer_dependencies,
+        key_order,
+    )?;
 
-    let mut change_sets: Vec<ChangeSet> = vec![];
+    let diff = match params.shows_diff && format == OutputFormat::Text && console_reporter.is_none()
+    {
+        true => verification.get_diff("package.json", key_order),
+        false => String::new(),
+    };
 
-    type_field_remover::validate(&package_json, &mut change_sets);
+    if let (Some(reporter), Some(report_file)) = (reporter, report_file) {
+        reporter.write(&verification.get_findings(), report_file)?;
+    }
 
-    if change_sets.is_empty() {
+    if verification.diagnostics.is_empty()
+        && format == OutputFormat::Text
+        && console_reporter.is_none()
+    {
         return Result::Ok(());
     }
 
-    if can_update_package_json {
-        package_json_content = apply_change_sets(&package_json_content, change_sets);
+    let mut output = match console_reporter {
+        Some(reporter) => reporter.render(&verification.get_findings()),
+        None => format.render(&verification.diagnostics),
+    };
+    if !diff.is_empty() {
+        output = format!("{}\n\n{}", output, diff);
+    }
+
+    // Only errors fail the verification, warnings and infos are reported all the same.
+    if verification.has_errors() {
+        return Result::Err(output);
+    }
+
+    if !output.is_empty() {
+        println!("{}", output);
+    }
+    Result::Ok(())
+}
+
+/// Verifies every package of a workspace, with output directories given relative to each of them.
+/// The verification fails when any package has errors.
+fn run_workspace_by_params(
+    workspace_root: &str,
+    relative_output_dirs: &[String],
+    params: &Params,
+) -> Result<(), String> {
+    let workspace_root = AbsolutePackageDir::new(workspace_root)?;
+    let key_order = KeyOrder::new(&params.key_order)?;
+    let format = OutputFormat::new(&params.format)?;
+    let reporter = params.reporter.as_deref().map(Reporter::new).transpose()?;
+    let report_file = params.report_file.as_deref();
+    let console_reporter = Reporter::for_console(reporter, report_file);
+
+    let package_paths = get_workspace_package_paths(&workspace_root)?;
+    if package_paths.is_empty() {
+        return Err(format!(
+            "No package matches the workspaces of {}",
+            workspace_root.value().display()
+        ));
+    }
 
-        fs::write(
-            absolute_package_dir.value().join("package.json"),
-            package_json_content,
+    let mut verifications = vec![];
+    for package_path in &package_paths {
+        let package_dir = workspace_root.value().join(package_path);
+        let absolute_package_dir = AbsolutePackageDir::new(&package_dir.to_string_lossy())?;
+        // Not every package is built into every output directory.
+        let absolute_output_dirs = relative_output_dirs
+            .iter()
+            .map(|relative_output_dir| package_dir.join(relative_output_dir))
+            .filter(|output_dir| output_dir.is_dir())
+            .map(|output_dir| AbsoluteOutputDir::new(&output_dir.to_string_lossy()))
+            .collect::<Result<Vec<_>, _>>()?;
+
+        let verification = verify_package(
+            &absolute_package_dir,
+            &absolute_output_dirs,
+            params.can_update_package_json,
+            params.prefers_peer_dependencies,
+            key_order,
         )
-        .expect("Failed to write package.json");
+        .map_err(|error| format!("{}: {}", package_path, error))?;
 
-        return Result::Ok(());
+        verifications.push((package_path.as_str(), verification));
     }
 
-    let desciptions: Vec<String> = change_sets
+    let findings: Vec<Finding> = verifications
         .iter()
-        .map(|change_set| change_set.description.clone())
+        .flat_map(|(_, verification)| verification.get_findings())
         .collect();
 
-    return Result::Err(desciptions.join("\n"));
+    if let (Some(reporter), Some(report_file)) = (reporter, report_file) {
+        reporter.write(&findings, report_file)?;
+    }
+
+    let mut output = match console_reporter {
+        Some(reporter) => reporter.render(&findings),
+        None => format.render_by_package(
+            &verifications
+                .iter()
+                .map(|(package_path, verification)| {
+                    (*package_path, verification.diagnostics.as_slice())
+                })
+                .collect::<Vec<_>>(),
+        ),
+    };
+
+    if params.shows_diff && format == OutputFormat::Text && console_reporter.is_none() {
+        // Paths are relative to the workspace root, so the whole diff applies from there at once.
+        let diff = verifications
+            .iter()
+            .map(|(package_path, verification)| {
+                verification.get_diff(&format!("{}/package.json", package_path), key_order)
+            })
+            .collect::<String>();
+
+        if !diff.is_empty() {
+            output = format!("{}\n\n{}", output, diff);
+        }
+    }
+
+    if verifications
+        .iter()
+        .any(|(_, verification)| verification.has_errors())
+    {
+        return Result::Err(output);
+    }
+
+    if !output.is_empty() {
+        println!("{}", output);
+    }
+    Result::Ok(())
+}
+
+/// The diagnostics of a single package, along with the package.json they point into.
+struct Verification {
+    package_dir: AbsolutePackageDir,
+    package_json_content: String,
+    diagnostics: Vec<Diagnostic>,
+}
+
+impl Verification {
+    fn has_errors(&self) -> bool {
+        self.diagnostics
+            .iter()
+            .any(|diagnostic| diagnostic.rule.severity == Severity::Error)
+    }
+
+    /// What package.json would look like with every fixable diagnostic fixed.
+    fn get_diff(&self, file_name: &str, key_order: KeyOrder) -> String {
+        let fixed_content = apply_change_sets(
+            &self.package_json_content,
+            get_fixable_change_sets(&self.diagnostics),
+            key_order,
+        );
+
+        get_unified_diff(file_name, &self.package_json_content, &fixed_content)
+    }
+
+    /// Places each diagnostic at the key it relates to, or at the closest ancestor when it is missing.
+    fn get_findings(&self) -> Vec<Finding> {
+        let root = json_spans::parse(&self.package_json_content);
+
+        self.diagnostics
+            .iter()
+            .map(|diagnostic| {
+                let keys = parse_json_pointer(&diagnostic.change_set.json_path);
+                let offset = root.find_offset(&keys);
+                let (line, column) = get_line_and_column(&self.package_json_content, offset);
+
+                Finding {
+                    rule_id: diagnostic.rule.id.to_string(),
+                    level: diagnostic.rule.severity.to_level(),
+                    message: diagnostic.change_set.description.clone(),
+                    file_path: self.package_dir.value().join("package.json"),
+                    line,
+                    column,
+                }
+            })
+            .collect()
+    }
+}
+
+/// Runs every rule that is turned on against a package, fixing what it can when allowed to.
+/// Fixed diagnostics are left out of the result.
+fn verify_package(
+    absolute_package_dir: &AbsolutePackageDir,
+    absolute_output_dirs: &[AbsoluteOutputDir],
+    can_update_package_json: bool,
+    prefers_peer_dependencies: bool,
+    key_order: KeyOrder,
+) -> Result<Verification, String> {
+    if absolute_output_dirs
+        .iter()
+        .any(|absolute_output_dir| !absolute_output_dir.is_package_dir_child(absolute_package_dir))
+    {
+        return Err("Output directory must be inside the package directory".to_string());
+    }
+
+    let mut package_json_content = get_package_json_string(absolute_package_dir);
+    let package_json = get_package_json_object(&package_json_content);
+    let rules_config = RulesConfig::load(absolute_package_dir, package_json.zoboz.as_ref(), RULES)?;
+    let mut diagnostics: Vec<Diagnostic> = vec![];
+    let mut check = |rule: Rule, validate: &dyn Fn(&mut Vec<ChangeSet>)| {
+        // Rules that are turned off don't run at all, as some of them fix files along the way.
+        let Some(severity) = rules_config.get_severity(&rule) else {
+            return;
+        };
+
+        let mut change_sets: Vec<ChangeSet> = vec![];
+        validate(&mut change_sets);
+        diagnostics.extend(change_sets.into_iter().map(|change_set| Diagnostic {
+            rule: Rule { severity, ..rule },
+            change_set,
+        }));
+    };
+
+    check(type_field_remover::RULE, &|change_sets| {
+        type_field_remover::validate(
+            &package_json,
+            &rules_config.get_option(&type_field_remover::RULE, "allow"),
+            change_sets,
+        )
+    });
+    check(runtime_dependencies_availability::RULE, &|change_sets| {
+        runtime_dependencies_availability::validate(
+            absolute_package_dir,
+            &package_json,
+            absolute_output_dirs,
+            prefers_peer_dependencies,
+            &rules_config.get_option(&runtime_dependencies_availability::RULE, "ignore"),
+            change_sets,
+        )
+    });
+    check(peer_dependencies_consistency::RULE, &|change_sets| {
+        peer_dependencies_consistency::validate(&package_json, change_sets)
+    });
+    check(peer_dependencies_consistency::META_RULE, &|change_sets| {
+        peer_dependencies_consistency::validate_meta(&package_json, change_sets)
+    });
+    check(dependency_protocols_publishability::RULE, &|change_sets| {
+        dependency_protocols_publishability::validate(
+            absolute_package_dir,
+            &package_json,
+            change_sets,
+        )
+    });
+    check(exports_targets_existence::RULE, &|change_sets| {
+        exports_targets_existence::validate(absolute_package_dir, &package_json, change_sets)
+    });
+    check(exports_conditions_order::RULE, &|change_sets| {
+        exports_conditions_order::validate(&package_json, change_sets)
+    });
+    check(published_files_inclusion::RULE, &|change_sets| {
+        published_files_inclusion::validate(absolute_package_dir, &package_json, change_sets)
+    });
+    check(side_effects_declaration::RULE, &|change_sets| {
+        side_effects_declaration::validate(
+            absolute_package_dir,
+            &package_json,
+            absolute_output_dirs,
+            change_sets,
+        )
+    });
+    check(
+        side_effects_declaration::TREE_SHAKING_RULE,
+        &|change_sets| {
+            side_effects_declaration::validate_tree_shaking(
+                absolute_package_dir,
+                &package_json,
+                absolute_output_dirs,
+                change_sets,
+            )
+        },
+    );
+    check(bin_entries_validity::RULE, &|change_sets| {
+        bin_entries_validity::validate(
+            absolute_package_dir,
+            &package_json,
+            can_update_package_json,
+            change_sets,
+        )
+    });
+    check(output_type_markers::RULE, &|change_sets| {
+        output_type_markers::validate(
+            absolute_package_dir,
+            absolute_output_dirs,
+            can_update_package_json,
+            change_sets,
+        )
+    });
+
+    if can_update_package_json {
+        let fixable_change_sets = get_fixable_change_sets(&diagnostics);
+
+        if !fixable_change_sets.is_empty() {
+            package_json_content =
+                apply_change_sets(&package_json_content, fixable_change_sets, key_order);
+
+            fs::write(
+                absolute_package_dir.value().join("package.json"),
+                &package_json_content,
+            )
+            .expect("Failed to write package.json");
+        }
+
+        // Diagnostics without any changes can't be fixed automatically, so they are still reported.
+        diagnostics.retain(|diagnostic| !diagnostic.is_fixable());
+    }
+
+    Ok(Verification {
+        package_dir: absolute_package_dir.clone(),
+        package_json_content,
+        diagnostics,
+    })
+}
+
+fn get_fixable_change_sets(diagnostics: &[Diagnostic]) -> Vec<ChangeSet> {
+    diagnostics
+        .iter()
+        .filter(|diagnostic| diagnostic.is_fixable())
+        .map(|diagnostic| diagnostic.change_set.clone())
+        .collect()
 }
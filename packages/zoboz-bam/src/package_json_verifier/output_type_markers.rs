@@ -0,0 +1,116 @@
+use std::fs;
+
+use crate::shared::json_editor::ChangeSet;
+use crate::shared::module_format::{detect_explicit_syntax_format, ModuleFormat};
+use crate::shared::type_marker::{is_type_marker, read_type_marker, write_type_marker};
+use crate::shared::utils;
+use crate::shared::value_objects::{AbsoluteOutputDir, AbsolutePackageDir};
+
+use super::diagnostic::{Rule, Severity};
+
+pub(crate) const RULE: Rule = Rule {
+    id: "output-type-markers",
+    severity: Severity::Error,
+    options: &[],
+};
+
+// Without a marker the package's own `type` decides how the `.js` files load, so that is where
+// these diagnostics point.
+const TYPE_FIELD_PATH: &str = "/type";
+
+/// Markers live in the output directories rather than package.json, so in fix-mode they are
+/// written right here and only what is left gets reported. `.mjs` and `.cjs` files don't need one.
+/// Only a package.json holding nothing but a `type` is overwritten, never the package's own one.
+pub(crate) fn validate(
+    package_dir: &AbsolutePackageDir,
+    output_dirs: &[AbsoluteOutputDir],
+    can_update_files: bool,
+    change_sets: &mut Vec<ChangeSet>,
+) {
+    for output_dir in output_dirs {
+        let relative_dir = match utils::relative(package_dir.value(), output_dir.value())
+            .to_string_lossy()
+            .replace('\\', "/")
+        {
+            relative_dir if relative_dir.is_empty() => ".".to_string(),
+            relative_dir => relative_dir,
+        };
+
+        let formats: Vec<ModuleFormat> = utils::list_files_recursively(output_dir.value(), &["js"])
+            .iter()
+            .filter_map(|file_path| fs::read_to_string(file_path).ok())
+            .filter_map(|file_content| detect_explicit_syntax_format(&file_content))
+            .collect();
+
+        let Some(&format) = formats.first() else {
+            continue;
+        };
+
+        if formats.iter().any(|other_format| *other_format != format) {
+            change_sets.push(ChangeSet {
+                description: format!(
+                    "Output directory `{}` mixes ESM and CJS `.js` files, so no `type` marker fits all of them; give one of them `.mjs` or `.cjs` extensions.",
+                    relative_dir
+                ),
+                json_path: TYPE_FIELD_PATH.to_string(),
+                changes: vec![],
+            });
+            continue;
+        }
+
+        let marker_type = read_type_marker(output_dir.value());
+        if marker_type.as_deref() == Some(format.get_type_field()) {
+            continue;
+        }
+
+        let is_package_json = output_dir.value() == package_dir.value()
+            || (output_dir.value().join("package.json").exists()
+                && !is_type_marker(output_dir.value()));
+
+        if can_update_files && is_package_json {
+            change_sets.push(ChangeSet {
+                description: format!(
+                    "Output directory `{}` needs `\"type\": \"{}\"`, but its package.json describes a package rather than marking a module format, so it isn't overwritten; set `type` in it by hand.",
+                    relative_dir,
+                    format.get_type_field()
+                ),
+                json_path: TYPE_FIELD_PATH.to_string(),
+                changes: vec![],
+            });
+            continue;
+        }
+
+        if can_update_files {
+            if let Err(e) = write_type_marker(output_dir.value(), format) {
+                change_sets.push(ChangeSet {
+                    description: format!(
+                        "Failed to write the `type` marker of `{}`: {}",
+                        relative_dir, e
+                    ),
+                    json_path: TYPE_FIELD_PATH.to_string(),
+                    changes: vec![],
+                });
+            }
+            continue;
+        }
+
+        change_sets.push(ChangeSet {
+            description: match marker_type {
+                Some(marker_type) => format!(
+                    "Output directory `{}` has {} `.js` files, but its package.json marker says `\"type\": \"{}\"`.",
+                    relative_dir,
+                    format.value(),
+                    marker_type
+                ),
+                None => format!(
+                    "Output directory `{}` has {} `.js` files, but no package.json marker with `\"type\": \"{}\"`; Node.js would load them as the package's `type` says.",
+                    relative_dir,
+                    format.value(),
+                    format.get_type_field()
+                ),
+            },
+            json_path: TYPE_FIELD_PATH.to_string(),
+            changes: vec![],
+        });
+    }
+}
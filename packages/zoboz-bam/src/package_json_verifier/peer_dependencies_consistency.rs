@@ -0,0 +1,87 @@
+use serde_json::Value;
+
+use crate::shared::json_editor::{to_json_pointer, Change, ChangeSet, ChangeType};
+use crate::shared::package_json_reader::PackageJson;
+use crate::shared::semver_range::Range;
+
+use super::diagnostic::{Rule, Severity};
+
+pub(crate) const RULE: Rule = Rule {
+    id: "peer-dependencies-consistency",
+    severity: Severity::Error,
+    options: &[],
+};
+
+// A stray meta entry is harmless, package managers just ignore it.
+pub(crate) const META_RULE: Rule = Rule {
+    id: "peer-dependencies-meta-consistency",
+    severity: Severity::Warn,
+    options: &[],
+};
+
+pub(crate) fn validate(package_json: &PackageJson, change_sets: &mut Vec<ChangeSet>) {
+    let mut peer_names: Vec<&String> = package_json.peer_dependencies.keys().collect();
+    peer_names.sort();
+
+    for peer_name in peer_names {
+        let peer_range = &package_json.peer_dependencies[peer_name];
+
+        let Some(dev_range) = package_json.dev_dependencies.get(peer_name) else {
+            change_sets.push(ChangeSet {
+                description: format!("Peer dependency `{}` has no counterpart in `devDependencies`, so it isn't installed while developing and testing the package.", peer_name),
+                json_path: to_json_pointer(&["peerDependencies", peer_name]),
+                changes: vec![Change {
+                    path: to_json_pointer(&["devDependencies", peer_name]),
+                    change_type: ChangeType::Add,
+                    value: Some(Value::String(peer_range.clone())),
+                }],
+            });
+            continue;
+        };
+
+        // Tags, URLs and protocols like `workspace:` aren't ranges, so there is nothing to compare.
+        let (Some(peer), Some(dev)) = (Range::parse(peer_range), Range::parse(dev_range)) else {
+            continue;
+        };
+
+        // The lowest version the dev range allows is what a fresh install may end up testing
+        // against, and the first one past the peer range is what a later install may pick up.
+        let outside_version = dev
+            .min_version()
+            .filter(|dev_version| !peer.is_satisfied_by(dev_version))
+            .or_else(|| {
+                peer.first_version_above().filter(|version| {
+                    dev.is_satisfied_by(version) && !peer.is_satisfied_by(version)
+                })
+            });
+
+        if let Some(dev_version) = outside_version {
+            change_sets.push(ChangeSet {
+                description: format!(
+                    "Dev dependency `{}` is `{}`, which allows `{}` outside of its peer range `{}`; tests could run against a version consumers can't use.",
+                    peer_name, dev_range, dev_version, peer_range
+                ),
+                json_path: to_json_pointer(&["devDependencies", peer_name]),
+                changes: vec![],
+            });
+        }
+    }
+}
+
+pub(crate) fn validate_meta(package_json: &PackageJson, change_sets: &mut Vec<ChangeSet>) {
+    let mut meta_names: Vec<&String> = package_json.peer_dependencies_meta.keys().collect();
+    meta_names.sort();
+
+    for meta_name in meta_names {
+        if !package_json.peer_dependencies.contains_key(meta_name) {
+            change_sets.push(ChangeSet {
+                description: format!(
+                    "`peerDependenciesMeta` describes `{}`, which is not listed in `peerDependencies`.",
+                    meta_name
+                ),
+                json_path: to_json_pointer(&["peerDependenciesMeta", meta_name]),
+                changes: vec![],
+            });
+        }
+    }
+}
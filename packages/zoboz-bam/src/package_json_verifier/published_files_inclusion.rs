@@ -0,0 +1,148 @@
+use std::collections::{HashSet, VecDeque};
+
+use crate::shared::json_editor::{to_json_pointer, ChangeSet};
+use crate::shared::npm_packlist::list_packed_files;
+use crate::shared::package_json_reader::PackageJson;
+use crate::shared::specifiers_scanner::collect_specifiers;
+use crate::shared::utils;
+use crate::shared::value_objects::AbsolutePackageDir;
+
+use super::diagnostic::{Rule, Severity};
+use super::exports_targets_existence::{collect_targets, get_target_pattern};
+
+pub(crate) const RULE: Rule = Rule {
+    id: "published-files-inclusion",
+    severity: Severity::Error,
+    options: &[],
+};
+
+const TRAVERSED_SUFFIXES: &[&str] = &[".js", ".mjs", ".cjs", ".d.ts", ".d.mts", ".d.cts"];
+
+pub(crate) fn validate(
+    package_dir: &AbsolutePackageDir,
+    package_json: &PackageJson,
+    change_sets: &mut Vec<ChangeSet>,
+) {
+    let packed_files: HashSet<String> = list_packed_files(package_dir, package_json)
+        .into_iter()
+        .collect();
+    let package_files = utils::list_relative_files(package_dir.value(), &["node_modules", ".git"]);
+
+    let mut queue: VecDeque<(String, String)> = VecDeque::new();
+
+    for (json_path, _, target) in collect_field_targets(package_json) {
+        let target = target.trim_start_matches("./");
+
+        if target.contains('*') {
+            let pattern = get_target_pattern(target);
+            for package_file in package_files.iter().filter(|file| pattern.is_match(file)) {
+                queue.push_back((package_file.clone(), format!("a target of `{}`", json_path)));
+            }
+        } else if let Some(target) = utils::normalize_relative_path(target) {
+            queue.push_back((target, format!("the target of `{}`", json_path)));
+        }
+    }
+
+    let mut visited: HashSet<String> = HashSet::new();
+
+    while let Some((relative_path, reason)) = queue.pop_front() {
+        if !visited.insert(relative_path.clone()) {
+            continue;
+        }
+
+        // Targets that don't exist at all are reported by `exports_targets_existence`.
+        if !package_dir.value().join(&relative_path).is_file() {
+            continue;
+        }
+
+        if !packed_files.contains(&relative_path) {
+            change_sets.push(ChangeSet {
+                description: format!("File `{}`, {}, is not included in the published package; add it to `files` or stop ignoring it.", relative_path, reason),
+                json_path: "/files".to_string(),
+                changes: vec![],
+            });
+        }
+
+        for dependency in collect_relative_dependencies(package_dir, &relative_path) {
+            queue.push_back((dependency, format!("imported by `{}`", relative_path)));
+        }
+    }
+}
+
+fn collect_field_targets(package_json: &PackageJson) -> Vec<(String, String, String)> {
+    let mut targets: Vec<(String, String, String)> = vec![];
+
+    for (field, value) in [
+        ("main", &package_json.main),
+        ("module", &package_json.module),
+        ("types", &package_json.types),
+        ("typings", &package_json.typings),
+    ] {
+        if let Some(value) = value {
+            targets.push((field.to_string(), to_json_pointer(&[field]), value.clone()));
+        }
+    }
+
+    for (command_name, bin_path) in package_json.get_bin_entries() {
+        targets.push((
+            format!("bin[\"{}\"]", command_name),
+            package_json.get_bin_json_path(&command_name),
+            bin_path,
+        ));
+    }
+
+    if let Some(exports) = &package_json.exports {
+        collect_targets(
+            exports,
+            "exports".to_string(),
+            vec!["exports".to_string()],
+            &mut targets,
+        );
+    }
+
+    targets
+}
+
+/// Follows the relative specifiers `reformat-specifiers` rewrote, declarations point at `.js`
+/// files whose declaration sits right next to them.
+fn collect_relative_dependencies(
+    package_dir: &AbsolutePackageDir,
+    relative_path: &str,
+) -> Vec<String> {
+    if !TRAVERSED_SUFFIXES
+        .iter()
+        .any(|suffix| relative_path.ends_with(suffix))
+    {
+        return vec![];
+    }
+
+    let is_declaration = relative_path.contains(".d.");
+    let dir = relative_path.rsplit_once('/').map_or("", |(dir, _)| dir);
+    let file_content =
+        std::fs::read_to_string(package_dir.value().join(relative_path)).unwrap_or_default();
+
+    collect_specifiers(&file_content)
+        .into_iter()
+        .filter(|specifier| specifier.starts_with("./") || specifier.starts_with("../"))
+        .filter_map(|specifier| utils::normalize_relative_path(&format!("{}/{}", dir, specifier)))
+        .map(|dependency| {
+            if is_declaration && !package_dir.value().join(&dependency).is_file() {
+                to_declaration_path(&dependency)
+            } else {
+                dependency
+            }
+        })
+        .collect()
+}
+
+fn to_declaration_path(path: &str) -> String {
+    for (extension, declaration_extension) in
+        [(".mjs", ".d.mts"), (".cjs", ".d.cts"), (".js", ".d.ts")]
+    {
+        if let Some(stem) = path.strip_suffix(extension) {
+            return format!("{}{}", stem, declaration_extension);
+        }
+    }
+
+    path.to_string()
+}
@@ -0,0 +1,160 @@
+use std::collections::HashMap;
+use std::fs;
+
+use serde_json::{Map, Value};
+
+use crate::shared::value_objects::AbsolutePackageDir;
+
+use super::diagnostic::{Rule, Severity};
+
+const CONFIG_FILE_NAME: &str = "zoboz.config.json";
+
+struct RuleSetting {
+    /// `None` when the rule is turned off.
+    severity: Option<Severity>,
+    options: HashMap<String, Vec<String>>,
+}
+
+/// The `rules` of the `zoboz` key in package.json, or of a zoboz.config.json next to it. Each one
+/// is set ESLint style, to `off`, `info`, `warn`, `error` or a `[severity, options]` pair.
+pub(crate) struct RulesConfig {
+    settings: HashMap<String, RuleSetting>,
+}
+
+impl RulesConfig {
+    pub(crate) fn load(
+        package_dir: &AbsolutePackageDir,
+        zoboz_field: Option<&Value>,
+        rules: &[Rule],
+    ) -> Result<Self, String> {
+        let config_path = package_dir.value().join(CONFIG_FILE_NAME);
+
+        let (config, source) = match (zoboz_field, config_path.is_file()) {
+            (Some(_), true) => {
+                return Err(format!(
+                    "zoboz is configured both by the `zoboz` key of package.json and by {}; keep only one of them",
+                    CONFIG_FILE_NAME
+                ))
+            }
+            (Some(config), false) => (config.clone(), "the `zoboz` key of package.json"),
+            (None, true) => {
+                let content = fs::read_to_string(&config_path)
+                    .map_err(|e| format!("Failed to read {}: {}", CONFIG_FILE_NAME, e))?;
+                let config = serde_json::from_str(&content)
+                    .map_err(|e| format!("{} is not valid JSON: {}", CONFIG_FILE_NAME, e))?;
+                (config, CONFIG_FILE_NAME)
+            }
+            (None, false) => (Value::Null, ""),
+        };
+
+        let rule_settings = match config.get("rules") {
+            None => &Map::new(),
+            Some(Value::Object(rule_settings)) => rule_settings,
+            Some(_) => return Err(format!("`rules` in {} must be an object", source)),
+        };
+
+        let mut settings = HashMap::new();
+
+        for (rule_id, value) in rule_settings {
+            let Some(rule) = rules.iter().find(|rule| rule.id == rule_id) else {
+                let rule_ids: Vec<&str> = rules.iter().map(|rule| rule.id).collect();
+                return Err(format!(
+                    "Unknown rule `{}` in {}; use one of `{}`",
+                    rule_id,
+                    source,
+                    rule_ids.join("`, `")
+                ));
+            };
+
+            let setting = parse_rule_setting(rule, value)
+                .map_err(|error| format!("{} in {}", error, source))?;
+            settings.insert(rule_id.clone(), setting);
+        }
+
+        Ok(RulesConfig { settings })
+    }
+
+    /// The configured severity of the rule, `None` when it is turned off.
+    pub(crate) fn get_severity(&self, rule: &Rule) -> Option<Severity> {
+        match self.settings.get(rule.id) {
+            Some(setting) => setting.severity,
+            None => Some(rule.severity),
+        }
+    }
+
+    pub(crate) fn get_option(&self, rule: &Rule, name: &str) -> Vec<String> {
+        self.settings
+            .get(rule.id)
+            .and_then(|setting| setting.options.get(name))
+            .cloned()
+            .unwrap_or_default()
+    }
+}
+
+fn parse_rule_setting(rule: &Rule, value: &Value) -> Result<RuleSetting, String> {
+    let (severity, options) = match value {
+        Value::String(severity) => (severity, None),
+        Value::Array(items) => match items.as_slice() {
+            [Value::String(severity)] => (severity, None),
+            [Value::String(severity), Value::Object(options)] => (severity, Some(options)),
+            _ => return Err(get_invalid_setting_error(rule)),
+        },
+        _ => return Err(get_invalid_setting_error(rule)),
+    };
+
+    let severity = match severity.as_str() {
+        "off" => None,
+        severity => Some(Severity::new(severity).ok_or_else(|| {
+            format!(
+                "Invalid severity '{}' for rule `{}`; use `off`, `info`, `warn` or `error`",
+                severity, rule.id
+            )
+        })?),
+    };
+
+    let mut parsed_options = HashMap::new();
+
+    for (name, value) in options.into_iter().flatten() {
+        if !rule.options.contains(&name.as_str()) {
+            return Err(if rule.options.is_empty() {
+                format!("Rule `{}` takes no options, but got `{}`", rule.id, name)
+            } else {
+                format!(
+                    "Unknown option `{}` for rule `{}`; use `{}`",
+                    name,
+                    rule.id,
+                    rule.options.join("`, `")
+                )
+            });
+        }
+
+        let values = value
+            .as_array()
+            .and_then(|items| {
+                items
+                    .iter()
+                    .map(|item| item.as_str().map(|item| item.to_string()))
+                    .collect::<Option<Vec<_>>>()
+            })
+            .ok_or_else(|| {
+                format!(
+                    "Option `{}` of rule `{}` must be an array of strings",
+                    name, rule.id
+                )
+            })?;
+
+        parsed_options.insert(name.clone(), values);
+    }
+
+    Ok(RuleSetting {
+        severity,
+        options: parsed_options,
+    })
+}
+
+fn get_invalid_setting_error(rule: &Rule) -> String {
+    format!(
+        "Rule `{}` must be set to a severity or to a `[severity, options]` pair",
+        rule.id
+    )
+}
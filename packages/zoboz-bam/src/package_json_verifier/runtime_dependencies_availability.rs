@@ -0,0 +1,109 @@
+use std::path::Path;
+
+use serde_json::Value;
+
+use crate::shared::json_editor::{to_json_pointer, Change, ChangeSet, ChangeType};
+use crate::shared::package_json_reader::PackageJson;
+use crate::shared::specifiers_scanner::collect_runtime_package_names;
+use crate::shared::value_objects::{AbsoluteOutputDir, AbsolutePackageDir};
+
+use super::diagnostic::{Rule, Severity};
+
+pub(crate) const RULE: Rule = Rule {
+    id: "runtime-dependencies-availability",
+    severity: Severity::Error,
+    // Packages that are fine to be missing, e.g. ones only required inside a `try`.
+    options: &["ignore"],
+};
+
+pub(crate) fn validate(
+    package_dir: &AbsolutePackageDir,
+    package_json: &PackageJson,
+    output_dirs: &[AbsoluteOutputDir],
+    prefers_peer_dependencies: bool,
+    ignored_packages: &[String],
+    change_sets: &mut Vec<ChangeSet>,
+) {
+    for package_name in collect_runtime_package_names(output_dirs) {
+        if package_json.name.as_deref() == Some(package_name.as_str())
+            || ignored_packages.contains(&package_name)
+            || package_json.dependencies.contains_key(&package_name)
+            || package_json.peer_dependencies.contains_key(&package_name)
+        {
+            continue;
+        }
+
+        if let Some(range) = package_json.dev_dependencies.get(&package_name) {
+            change_sets.push(ChangeSet {
+                description: format!("Runtime dependency `{}` is only listed in `devDependencies`. Move it to `dependencies`, or duplicate it in `peerDependencies` to let consumers bring their own version.", package_name),
+                json_path: to_json_pointer(&["devDependencies", &package_name]),
+                changes: if prefers_peer_dependencies {
+                    vec![add_change("peerDependencies", &package_name, range)]
+                } else {
+                    vec![Change {
+                        path: to_json_pointer(&["devDependencies", &package_name]),
+                        change_type: ChangeType::MoveKey(to_json_pointer(&[
+                            "dependencies",
+                            &package_name,
+                        ])),
+                        value: None,
+                    }]
+                },
+            });
+            continue;
+        }
+
+        let range = get_installed_range(package_dir, &package_name);
+
+        change_sets.push(ChangeSet {
+            description: format!("Runtime dependency `{}` is not listed in package.json. Add it to `dependencies`, or to both `devDependencies` and `peerDependencies` to let consumers bring their own version.", package_name),
+            json_path: if prefers_peer_dependencies {
+                to_json_pointer(&["peerDependencies", &package_name])
+            } else {
+                to_json_pointer(&["dependencies", &package_name])
+            },
+            changes: if prefers_peer_dependencies {
+                vec![
+                    add_change("devDependencies", &package_name, &range),
+                    add_change("peerDependencies", &package_name, &range),
+                ]
+            } else {
+                vec![add_change("dependencies", &package_name, &range)]
+            },
+        });
+    }
+}
+
+/// Looks the package up in the nearest `node_modules`, falling back to `*` when it isn't installed.
+fn get_installed_range(package_dir: &AbsolutePackageDir, package_name: &str) -> String {
+    let installed_version = package_dir.value().ancestors().find_map(|dir| {
+        read_version(
+            &dir.join("node_modules")
+                .join(package_name)
+                .join("package.json"),
+        )
+    });
+
+    match installed_version {
+        Some(version) => format!("^{}", version),
+        None => "*".to_string(),
+    }
+}
+
+fn read_version(package_json_path: &Path) -> Option<String> {
+    let package_json_content = std::fs::read_to_string(package_json_path).ok()?;
+    let package_json: serde_json::Value = serde_json::from_str(&package_json_content).ok()?;
+
+    package_json
+        .get("version")
+        .and_then(|version| version.as_str())
+        .map(|version| version.to_string())
+}
+
+fn add_change(field: &str, package_name: &str, range: &str) -> Change {
+    Change {
+        path: to_json_pointer(&[field, package_name]),
+        change_type: ChangeType::Add,
+        value: Some(Value::String(range.to_string())),
+    }
+}
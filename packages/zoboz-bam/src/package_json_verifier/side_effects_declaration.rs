@@ -0,0 +1,198 @@
+use std::collections::BTreeMap;
+use std::path::Path;
+
+use serde_json::Value;
+
+use crate::shared::glob_matcher;
+use crate::shared::json_editor::{Change, ChangeSet, ChangeType};
+use crate::shared::package_json_reader::PackageJson;
+use crate::shared::side_effects_scanner::{find_top_level_side_effects, SideEffect};
+use crate::shared::utils;
+use crate::shared::value_objects::{AbsoluteOutputDir, AbsolutePackageDir};
+
+use super::diagnostic::{Rule, Severity};
+
+pub(crate) const RULE: Rule = Rule {
+    id: "side-effects-declaration",
+    severity: Severity::Error,
+    options: &[],
+};
+
+// Declaring more side effects than there are only costs bundle size.
+pub(crate) const TREE_SHAKING_RULE: Rule = Rule {
+    id: "side-effects-tree-shaking",
+    severity: Severity::Info,
+    options: &[],
+};
+
+const RUNTIME_EXTENSIONS: &[&str] = &["js", "jsx", "mjs", "cjs"];
+
+/// Without a `sideEffects` field bundlers already assume the worst, so only a declared one is checked.
+pub(crate) fn validate(
+    package_dir: &AbsolutePackageDir,
+    package_json: &PackageJson,
+    output_dirs: &[AbsoluteOutputDir],
+    change_sets: &mut Vec<ChangeSet>,
+) {
+    let Some(side_effects) = &package_json.side_effects else {
+        return;
+    };
+
+    if output_dirs.is_empty() {
+        return;
+    }
+
+    let effectful_files = collect_effectful_files(package_dir, output_dirs);
+
+    let patterns: Vec<String> = match side_effects {
+        Value::Array(patterns) => patterns
+            .iter()
+            .filter_map(|pattern| pattern.as_str())
+            .map(|pattern| pattern.to_string())
+            .collect(),
+        Value::Bool(false) => vec![],
+        _ => return,
+    };
+
+    let undeclared_files: Vec<(String, String)> = effectful_files
+        .into_iter()
+        .filter(|(relative_path, _)| !is_declared(&patterns, relative_path))
+        .collect();
+
+    // Appending keeps each fix independent, but `false` has to become an array in one go.
+    let declared_side_effects: Vec<Value> = patterns
+        .into_iter()
+        .map(Value::String)
+        .chain(
+            undeclared_files
+                .iter()
+                .map(|(relative_path, _)| Value::String(format!("./{}", relative_path))),
+        )
+        .collect();
+
+    for (relative_path, reason) in undeclared_files {
+        let change = if side_effects.is_array() {
+            Change {
+                path: "/sideEffects".to_string(),
+                change_type: ChangeType::ArrayAppend,
+                value: Some(Value::String(format!("./{}", relative_path))),
+            }
+        } else {
+            update_change(Value::Array(declared_side_effects.clone()))
+        };
+
+        change_sets.push(ChangeSet {
+            description: format!(
+                "File `{}` {}, but `sideEffects` marks it as side-effect free; list it in `sideEffects`.",
+                relative_path, reason
+            ),
+            json_path: "/sideEffects".to_string(),
+            changes: vec![change],
+        });
+    }
+}
+
+pub(crate) fn validate_tree_shaking(
+    package_dir: &AbsolutePackageDir,
+    package_json: &PackageJson,
+    output_dirs: &[AbsoluteOutputDir],
+    change_sets: &mut Vec<ChangeSet>,
+) {
+    let Some(side_effects) = &package_json.side_effects else {
+        return;
+    };
+
+    if output_dirs.is_empty() || side_effects == &Value::Bool(false) {
+        return;
+    }
+
+    if collect_effectful_files(package_dir, output_dirs).is_empty() {
+        change_sets.push(ChangeSet {
+            description: "No file in the output has top-level side effects; set `sideEffects` to `false` to let bundlers tree-shake the package.".to_string(),
+            json_path: "/sideEffects".to_string(),
+            changes: vec![update_change(Value::Bool(false))],
+        });
+    }
+}
+
+/// Maps package relative paths to why they have side effects. Relative side-effect imports of
+/// JS files are left to the imported file itself, other assets like CSS get reported instead.
+fn collect_effectful_files(
+    package_dir: &AbsolutePackageDir,
+    output_dirs: &[AbsoluteOutputDir],
+) -> BTreeMap<String, String> {
+    let mut effectful_files: BTreeMap<String, String> = BTreeMap::new();
+
+    let file_paths = output_dirs.iter().flat_map(|output_dir| {
+        utils::list_files_recursively(output_dir.value(), RUNTIME_EXTENSIONS)
+    });
+
+    for file_path in file_paths {
+        let Ok(file_content) = std::fs::read_to_string(&file_path) else {
+            continue;
+        };
+        let relative_path = get_relative_path(package_dir, &file_path);
+
+        for side_effect in find_top_level_side_effects(&file_content) {
+            if let SideEffect::Import(specifier) = &side_effect {
+                if specifier.starts_with("./") || specifier.starts_with("../") {
+                    let imported_path = match relative_path.rsplit_once('/') {
+                        Some((relative_dir, _)) => utils::normalize_relative_path(&format!(
+                            "{}/{}",
+                            relative_dir, specifier
+                        )),
+                        None => utils::normalize_relative_path(specifier),
+                    };
+
+                    if let Some(imported_path) = imported_path {
+                        let is_runtime_file = Path::new(&imported_path)
+                            .extension()
+                            .and_then(|extension| extension.to_str())
+                            .is_some_and(|extension| RUNTIME_EXTENSIONS.contains(&extension));
+
+                        if !is_runtime_file && package_dir.value().join(&imported_path).is_file() {
+                            effectful_files.entry(imported_path).or_insert(format!(
+                                "is imported for its side effects by `{}`",
+                                relative_path
+                            ));
+                        }
+                    }
+                    continue;
+                }
+            }
+
+            effectful_files
+                .entry(relative_path.clone())
+                .or_insert(format!("has {}", side_effect.describe()));
+        }
+    }
+
+    effectful_files
+}
+
+/// Like webpack, patterns without a `/` match the file name anywhere in the package.
+fn is_declared(patterns: &[String], relative_path: &str) -> bool {
+    patterns.iter().any(|pattern| {
+        let pattern = pattern.strip_prefix("./").unwrap_or(pattern);
+
+        if pattern.contains('/') {
+            glob_matcher::is_match(pattern, relative_path)
+        } else {
+            glob_matcher::is_match(&format!("**/{}", pattern), relative_path)
+        }
+    })
+}
+
+fn get_relative_path(package_dir: &AbsolutePackageDir, file_path: &Path) -> String {
+    utils::relative(package_dir.value(), file_path)
+        .to_string_lossy()
+        .replace('\\', "/")
+}
+
+fn update_change(value: Value) -> Change {
+    Change {
+        path: "/sideEffects".to_string(),
+        change_type: ChangeType::Update,
+        value: Some(value),
+    }
+}
@@ -2,13 +2,31 @@ use crate::shared::json_editor::{Change, ChangeSet, ChangeType};
 
 use crate::shared::package_json_reader::PackageJson;
 
-pub(crate) fn validate(package_json: &PackageJson, change_sets: &mut Vec<ChangeSet>) {
-    if package_json.type_field.is_some() {
+use super::diagnostic::{Rule, Severity};
+
+pub(crate) const RULE: Rule = Rule {
+    id: "type-field-remover",
+    severity: Severity::Error,
+    // `type` values the package keeps on purpose, e.g. `["module"]`.
+    options: &["allow"],
+};
+
+pub(crate) fn validate(
+    package_json: &PackageJson,
+    allowed_types: &[String],
+    change_sets: &mut Vec<ChangeSet>,
+) {
+    if package_json
+        .type_field
+        .as_ref()
+        .is_some_and(|type_field| !allowed_types.contains(type_field))
+    {
         change_sets.push(ChangeSet {
             description: "Field `type` in package.json should not exist. https://github.com/dariushalipour/zoboz/blob/main/packages/zoboz-bam/src/package_json_verifier/README.md".to_string(),
+            json_path: "/type".to_string(),
             changes: vec![
                 Change {
-                    path: "type".to_string(),
+                    path: "/type".to_string(),
                     change_type: ChangeType::Remove,
                     value: None,
                 }
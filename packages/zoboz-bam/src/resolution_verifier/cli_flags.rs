@@ -0,0 +1,37 @@
+pub(super) fn get_params(args: &[String]) -> Result<(String, Vec<String>), String> {
+    let absolute_package_dir = get_absolute_package_dir(args)?;
+    let modes = get_modes(args);
+
+    Ok((absolute_package_dir, modes))
+}
+
+fn get_absolute_package_dir(args: &[String]) -> Result<String, String> {
+    let absolute_package_dir = args.iter().position(|arg| arg == "--absolute-package-dir");
+    let absolute_package_dir = match absolute_package_dir {
+        Some(index) => args.get(index + 1),
+        None => None,
+    };
+
+    match absolute_package_dir {
+        Some(value) => Ok(value.to_string()),
+        None => {
+            Err("--absolute-package-dir not found; use --absolute-package-dir <path>".to_owned())
+        }
+    }
+}
+
+fn get_modes(args: &[String]) -> Vec<String> {
+    let modes = args.iter().position(|arg| arg == "--modes");
+    let modes = match modes {
+        Some(index) => args.get(index + 1),
+        None => None,
+    };
+
+    match modes {
+        Some(value) => value
+            .split(',')
+            .map(|mode| mode.trim().to_string())
+            .collect(),
+        None => vec![],
+    }
+}
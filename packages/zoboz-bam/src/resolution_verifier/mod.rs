@@ -0,0 +1,186 @@
+mod cli_flags;
+mod resolution_modes;
+
+use std::path::{Path, PathBuf};
+
+use cli_flags::get_params;
+use resolution_modes::{ResolutionMode, MODE_NAMES};
+use serde_json::Value;
+
+use crate::shared::{
+    module_format::{detect_runtime_format, detect_types_format},
+    package_json_reader::get_package_json_string,
+    utils,
+    value_objects::AbsolutePackageDir,
+};
+
+pub fn run_by_args(args: &[String]) -> Result<(), String> {
+    let (absolute_package_dir, modes) = get_params(args)?;
+
+    run_by_params(&absolute_package_dir, &modes)
+}
+
+pub fn run_by_params(absolute_package_dir: &str, modes: &[String]) -> Result<(), String> {
+    let package_dir = AbsolutePackageDir::new(absolute_package_dir)?;
+
+    let mode_names: Vec<&str> = if modes.is_empty() {
+        MODE_NAMES.to_vec()
+    } else {
+        modes.iter().map(|mode| mode.as_str()).collect()
+    };
+    let modes = mode_names
+        .into_iter()
+        .map(ResolutionMode::new)
+        .collect::<Result<Vec<_>, _>>()?;
+
+    let package_json: Value = serde_json::from_str(&get_package_json_string(&package_dir))
+        .map_err(|e| format!("Failed to parse package.json: {}", e))?;
+    let exports = package_json.get("exports");
+    let package_name = package_json.get("name").and_then(|name| name.as_str());
+
+    if exports.is_some() && package_name.is_none() {
+        return Err(
+            "Field `name` in package.json is required to resolve \"exports\" as a consumer would"
+                .to_string(),
+        );
+    }
+
+    let subpaths = get_subpaths(exports);
+
+    let mut rows: Vec<Vec<String>> = vec![];
+    let mut details: Vec<String> = vec![];
+
+    for subpath in &subpaths {
+        let mut row = vec![subpath.clone()];
+
+        for mode in &modes {
+            // Without "exports", every mode falls back to resolving paths inside the package.
+            let specifier = match package_name {
+                Some(package_name) if mode.supports_exports && exports.is_some() => {
+                    format!("{}{}", package_name, subpath.trim_start_matches('.'))
+                }
+                _ => subpath.clone(),
+            };
+
+            let runtime = resolve(&mode.runtime_resolver, &package_dir, &specifier);
+            let types = resolve(&mode.types_resolver, &package_dir, &specifier);
+
+            let outcome = match (&runtime, &types) {
+                (Some(runtime), Some(types)) => {
+                    let runtime_format = detect_runtime_format(runtime);
+                    let types_format = detect_types_format(types);
+
+                    if mode.detects_format && runtime_format != types_format {
+                        details.push(format!(
+                            "`{}` under {}: types `{}` are {} but runtime `{}` is {} (masquerading as {})",
+                            subpath,
+                            mode.name,
+                            to_relative(&package_dir, types),
+                            types_format.value(),
+                            to_relative(&package_dir, runtime),
+                            runtime_format.value(),
+                            types_format.value(),
+                        ));
+                        "mismatch"
+                    } else {
+                        "pass"
+                    }
+                }
+                (Some(runtime), None) => {
+                    details.push(format!(
+                        "`{}` under {}: runtime resolves to `{}` but types fail to resolve",
+                        subpath,
+                        mode.name,
+                        to_relative(&package_dir, runtime)
+                    ));
+                    "mismatch"
+                }
+                (None, Some(types)) => {
+                    details.push(format!(
+                        "`{}` under {}: types resolve to `{}` but runtime fails to resolve",
+                        subpath,
+                        mode.name,
+                        to_relative(&package_dir, types)
+                    ));
+                    "mismatch"
+                }
+                (None, None) => {
+                    details.push(format!(
+                        "`{}` under {}: neither runtime nor types resolve",
+                        subpath, mode.name
+                    ));
+                    "fail"
+                }
+            };
+
+            row.push(outcome.to_string());
+        }
+
+        rows.push(row);
+    }
+
+    let mut header = vec!["Subpath".to_string()];
+    header.extend(modes.iter().map(|mode| mode.name.clone()));
+
+    let matrix = render_matrix(&header, &rows);
+
+    if details.is_empty() {
+        println!("{}", matrix);
+        return Ok(());
+    }
+
+    Err(format!("{}\n\n{}", matrix, details.join("\n")))
+}
+
+/// Pattern subpaths can't be resolved without a concrete name and `null` ones are exclusions.
+fn get_subpaths(exports: Option<&Value>) -> Vec<String> {
+    match exports {
+        Some(Value::Object(entries)) if entries.keys().any(|key| key.starts_with('.')) => entries
+            .iter()
+            .filter(|(subpath, target)| !subpath.contains('*') && !target.is_null())
+            .map(|(subpath, _)| subpath.clone())
+            .collect(),
+        _ => vec![".".to_string()],
+    }
+}
+
+fn resolve(
+    resolver: &oxc_resolver::Resolver,
+    package_dir: &AbsolutePackageDir,
+    specifier: &str,
+) -> Option<PathBuf> {
+    let resolution = resolver.resolve(package_dir.value(), specifier).ok()?;
+
+    Some(resolution.path().to_path_buf())
+}
+
+fn to_relative(package_dir: &AbsolutePackageDir, path: &Path) -> String {
+    utils::ensure_relative_prefix(utils::relative(package_dir.value(), path))
+}
+
+fn render_matrix(header: &[String], rows: &[Vec<String>]) -> String {
+    let widths: Vec<usize> = (0..header.len())
+        .map(|column| {
+            rows.iter()
+                .map(|row| row[column].len())
+                .chain([header[column].len()])
+                .max()
+                .unwrap_or_default()
+        })
+        .collect();
+
+    [header.to_vec()]
+        .iter()
+        .chain(rows)
+        .map(|row| {
+            row.iter()
+                .zip(&widths)
+                .map(|(cell, width)| format!("{:width$}", cell, width = width))
+                .collect::<Vec<_>>()
+                .join("  ")
+                .trim_end()
+                .to_string()
+        })
+        .collect::<Vec<_>>()
+        .join("\n")
+}
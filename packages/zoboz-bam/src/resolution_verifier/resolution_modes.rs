@@ -0,0 +1,76 @@
+use oxc_resolver::{ResolveOptions, Resolver};
+
+pub(super) const MODE_NAMES: &[&str] = &["node10", "node16-cjs", "node16-esm", "bundler"];
+
+/// A consumer's point of view, resolving runtime JS and declarations with its own rules.
+pub(super) struct ResolutionMode {
+    pub name: String,
+    pub runtime_resolver: Resolver,
+    pub types_resolver: Resolver,
+    pub supports_exports: bool,
+    /// Whether TypeScript tells ESM and CJS declarations apart in this mode.
+    pub detects_format: bool,
+}
+
+impl ResolutionMode {
+    pub(super) fn new(name: &str) -> Result<Self, String> {
+        let (runtime_conditions, types_conditions, supports_exports): (&[&str], &[&str], bool) =
+            match name {
+                "node10" => (&["node", "require"], &["types", "node", "require"], false),
+                "node16-cjs" => (&["node", "require"], &["types", "node", "require"], true),
+                "node16-esm" => (&["node", "import"], &["types", "node", "import"], true),
+                "bundler" => (&["import", "module"], &["types", "import"], true),
+                _ => {
+                    return Err(format!(
+                        "Resolution mode '{}' is not among valid options: '{}'",
+                        name,
+                        MODE_NAMES.join("','")
+                    ))
+                }
+            };
+
+        let runtime_main_fields: &[&str] = if name == "bundler" {
+            &["module", "main"]
+        } else {
+            &["main"]
+        };
+
+        Ok(Self {
+            name: name.to_string(),
+            runtime_resolver: Resolver::new(ResolveOptions {
+                condition_names: to_strings(runtime_conditions),
+                main_fields: to_strings(runtime_main_fields),
+                exports_fields: get_exports_fields(supports_exports),
+                extensions: to_strings(&[".js", ".json", ".node"]),
+                ..Default::default()
+            }),
+            types_resolver: Resolver::new(ResolveOptions {
+                condition_names: to_strings(types_conditions),
+                main_fields: to_strings(&["types", "typings", "main"]),
+                exports_fields: get_exports_fields(supports_exports),
+                extensions: to_strings(&[".d.ts"]),
+                // Like TypeScript, a JS target is typed by the declaration sitting next to it.
+                extension_alias: vec![
+                    (".js".to_string(), to_strings(&[".d.ts"])),
+                    (".mjs".to_string(), to_strings(&[".d.mts"])),
+                    (".cjs".to_string(), to_strings(&[".d.cts"])),
+                ],
+                ..Default::default()
+            }),
+            supports_exports,
+            detects_format: name.starts_with("node16"),
+        })
+    }
+}
+
+fn get_exports_fields(supports_exports: bool) -> Vec<Vec<String>> {
+    if supports_exports {
+        vec![vec!["exports".to_string()]]
+    } else {
+        vec![]
+    }
+}
+
+fn to_strings(values: &[&str]) -> Vec<String> {
+    values.iter().map(|value| value.to_string()).collect()
+}
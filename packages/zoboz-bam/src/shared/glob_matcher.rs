@@ -0,0 +1,103 @@
+/// Turns a glob into an anchored regex over `/` separated relative paths.
+/// `**` spans directories, `*` and `?` stay within one segment, `[...]` is a character class.
+pub fn glob_to_regex(pattern: &str) -> regex::Regex {
+    let mut regex = String::from("^");
+    let chars: Vec<char> = pattern.chars().collect();
+    let mut index = 0;
+
+    while index < chars.len() {
+        match chars[index] {
+            '*' if chars.get(index + 1) == Some(&'*') => {
+                if chars.get(index + 2) == Some(&'/') {
+                    regex.push_str("(?:.*/)?");
+                    index += 3;
+                } else {
+                    regex.push_str(".*");
+                    index += 2;
+                }
+                continue;
+            }
+            '*' => regex.push_str("[^/]*"),
+            '?' => regex.push_str("[^/]"),
+            '[' => match chars[index..].iter().position(|c| *c == ']') {
+                Some(length) => {
+                    let class: String = chars[index + 1..index + length].iter().collect();
+                    let class = class
+                        .strip_prefix('!')
+                        .map_or(class.clone(), |negated| format!("^{}", negated));
+                    regex.push_str(&format!("[{}]", class.replace('\\', "\\\\")));
+                    index += length + 1;
+                    continue;
+                }
+                None => regex.push_str(r"\["),
+            },
+            c => regex.push_str(&regex::escape(&c.to_string())),
+        }
+        index += 1;
+    }
+
+    regex.push('$');
+
+    regex::Regex::new(&regex).unwrap_or_else(|_| regex::Regex::new("$^").unwrap())
+}
+
+pub fn is_match(pattern: &str, path: &str) -> bool {
+    glob_to_regex(pattern).is_match(path)
+}
+
+/// A single line of a `.gitignore` or `.npmignore` file.
+pub struct IgnoreRule {
+    regex: regex::Regex,
+    negated: bool,
+    dir_only: bool,
+}
+
+impl IgnoreRule {
+    pub fn parse(line: &str) -> Option<Self> {
+        let line = line.trim_end();
+        if line.is_empty() || line.starts_with('#') {
+            return None;
+        }
+
+        let (negated, line) = match line.strip_prefix('!') {
+            Some(line) => (true, line),
+            None => (false, line.strip_prefix('\\').unwrap_or(line)),
+        };
+
+        let (dir_only, line) = match line.strip_suffix('/') {
+            Some(line) => (true, line),
+            None => (false, line),
+        };
+
+        // Without a slash in the middle, a pattern matches at any depth.
+        let pattern = if line.contains('/') {
+            line.trim_start_matches('/').to_string()
+        } else {
+            format!("**/{}", line)
+        };
+
+        Some(Self {
+            regex: glob_to_regex(&pattern),
+            negated,
+            dir_only,
+        })
+    }
+
+    pub fn parse_all(content: &str) -> Vec<Self> {
+        content.lines().filter_map(Self::parse).collect()
+    }
+
+    pub fn is_negated(&self) -> bool {
+        self.negated
+    }
+
+    /// Matches the file itself or any directory containing it, since ignoring a directory ignores its content.
+    pub fn is_match(&self, relative_path: &str) -> bool {
+        let segments: Vec<&str> = relative_path.split('/').collect();
+
+        (1..segments.len())
+            .map(|length| segments[..length].join("/"))
+            .any(|dir| self.regex.is_match(&dir))
+            || (!self.dir_only && self.regex.is_match(relative_path))
+    }
+}
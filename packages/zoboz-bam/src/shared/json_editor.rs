@@ -1,68 +1,675 @@
-use serde_json::{Map, Value};
+use serde::Serialize;
+use serde_json::{ser::PrettyFormatter, Map, Serializer, Value};
 
+use super::json_spans::{self, JsonNode, JsonNodeKind};
+
+// The top-level field order sort-package-json enforces, trimmed to the fields packages commonly use.
+const PACKAGE_JSON_FIELD_ORDER: &[&str] = &[
+    "$schema",
+    "name",
+    "displayName",
+    "version",
+    "private",
+    "description",
+    "keywords",
+    "homepage",
+    "bugs",
+    "repository",
+    "funding",
+    "license",
+    "author",
+    "maintainers",
+    "contributors",
+    "sideEffects",
+    "type",
+    "imports",
+    "exports",
+    "main",
+    "umd:main",
+    "jsdelivr",
+    "unpkg",
+    "module",
+    "source",
+    "browser",
+    "react-native",
+    "types",
+    "typesVersions",
+    "typings",
+    "style",
+    "bin",
+    "man",
+    "directories",
+    "files",
+    "workspaces",
+    "scripts",
+    "config",
+    "resolutions",
+    "dependencies",
+    "devDependencies",
+    "dependenciesMeta",
+    "peerDependencies",
+    "peerDependenciesMeta",
+    "optionalDependencies",
+    "bundledDependencies",
+    "bundleDependencies",
+    "packageManager",
+    "engines",
+    "volta",
+    "os",
+    "cpu",
+    "publishConfig",
+];
+
+// Fields sort-package-json keeps sorted by key.
+const SORTED_FIELDS: &[&str] = &[
+    "dependencies",
+    "devDependencies",
+    "dependenciesMeta",
+    "peerDependencies",
+    "peerDependenciesMeta",
+    "optionalDependencies",
+    "resolutions",
+];
+
+#[derive(Clone)]
 pub struct ChangeSet {
     pub description: String,
+    /// A JSON Pointer to the value the change set is about, which may not exist yet.
+    pub json_path: String,
     pub changes: Vec<Change>,
 }
 
+#[derive(Clone)]
 pub struct Change {
+    /// An RFC 6901 JSON Pointer, such as `/exports/.~1feature.js/import`.
     pub path: String,
     pub change_type: ChangeType,
-    pub value: Option<String>,
+    pub value: Option<Value>,
 }
 
+#[derive(Clone)]
 pub enum ChangeType {
     Add,
     Remove,
     Update,
+    /// Pushes the value onto the array at the path, creating the array when missing.
+    ArrayAppend,
+    /// Drops every item equal to the value from the array at the path.
+    ArrayRemove,
+    /// Moves the key at the path to the given JSON Pointer, inserted there like a new key.
+    MoveKey(String),
+    /// Renames the key at the path, keeping its position among its siblings. When a sibling
+    /// already has the new name, the value is merged into it instead: objects key by key,
+    /// anything else replaces it.
+    RenameKey(String),
 }
 
-pub fn apply_change_sets(json_str: &str, change_sets: Vec<ChangeSet>) -> String {
-    // This is causing issues with the order of the keys in the JSON
-    let mut json_value: Value = serde_json::from_str(json_str).expect("Invalid JSON");
+/// Where keys that don't exist yet get inserted among their siblings.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum KeyOrder {
+    #[default]
+    Append,
+    /// Known top-level fields in sort-package-json's order, dependencies alphabetically.
+    SortPackageJson,
+}
 
-    for change_set in change_sets {
-        apply_change_set(&mut json_value, change_set);
+impl KeyOrder {
+    pub fn new(key_order: &str) -> Result<Self, String> {
+        match key_order {
+            "append" => Ok(KeyOrder::Append),
+            "sort-package-json" => Ok(KeyOrder::SortPackageJson),
+            _ => Err(format!(
+                "Invalid key order '{}'; use `append` or `sort-package-json`",
+                key_order
+            )),
+        }
+    }
+
+    fn get_insert_index(&self, parent_keys: &[String], sibling_keys: &[&str], key: &str) -> usize {
+        let index = match (self, parent_keys) {
+            (KeyOrder::Append, _) => None,
+            (KeyOrder::SortPackageJson, []) => get_field_rank(key).and_then(|rank| {
+                // Unknown fields go after every known one.
+                sibling_keys
+                    .iter()
+                    .position(|sibling| get_field_rank(sibling).is_none_or(|other| other > rank))
+            }),
+            (KeyOrder::SortPackageJson, [field]) if SORTED_FIELDS.contains(&field.as_str()) => {
+                sibling_keys.iter().position(|sibling| *sibling > key)
+            }
+            (KeyOrder::SortPackageJson, _) => None,
+        };
+
+        index.unwrap_or(sibling_keys.len())
+    }
+}
+
+fn get_field_rank(field: &str) -> Option<usize> {
+    PACKAGE_JSON_FIELD_ORDER
+        .iter()
+        .position(|known_field| *known_field == field)
+}
+
+/// Builds a JSON Pointer out of raw keys, escaping `~` and `/` in each of them.
+pub fn to_json_pointer(keys: &[&str]) -> String {
+    keys.iter()
+        .map(|key| format!("/{}", key.replace('~', "~0").replace('/', "~1")))
+        .collect()
+}
+
+/// The empty pointer refers to the whole document.
+pub fn parse_json_pointer(json_pointer: &str) -> Vec<String> {
+    if json_pointer.is_empty() {
+        return vec![];
     }
 
-    serde_json::to_string_pretty(&json_value).expect("Failed to serialize JSON")
+    json_pointer
+        .strip_prefix('/')
+        .expect("Change path must be a JSON Pointer starting with `/`")
+        .split('/')
+        .map(|key| key.replace("~1", "/").replace("~0", "~"))
+        .collect()
+}
+
+/// The indentation and line endings of the original text, reused for everything written into it.
+struct JsonStyle {
+    indent: String,
+    newline: &'static str,
+}
+
+impl JsonStyle {
+    fn detect(json_str: &str) -> Self {
+        let indent = json_str
+            .lines()
+            .skip(1)
+            .find_map(|line| {
+                let content = line.trim_start_matches([' ', '\t']);
+                (content.len() < line.len() && !content.trim().is_empty())
+                    .then(|| line[..line.len() - content.len()].to_string())
+            })
+            .unwrap_or_else(|| "  ".to_string());
+
+        JsonStyle {
+            indent,
+            newline: if json_str.contains("\r\n") {
+                "\r\n"
+            } else {
+                "\n"
+            },
+        }
+    }
+
+    /// Pretty prints the value as if it started on a line indented by `base_indent`,
+    /// or on a single line when there is no indentation to follow.
+    fn serialize(&self, value: &Value, base_indent: Option<&str>) -> String {
+        let Some(base_indent) = base_indent else {
+            return serde_json::to_string(value).expect("Failed to serialize JSON");
+        };
+
+        let mut buffer = vec![];
+        let mut serializer = Serializer::with_formatter(
+            &mut buffer,
+            PrettyFormatter::with_indent(self.indent.as_bytes()),
+        );
+        value
+            .serialize(&mut serializer)
+            .expect("Failed to serialize JSON");
+
+        String::from_utf8(buffer)
+            .expect("Failed to serialize JSON")
+            .replace('\n', &format!("{}{}", self.newline, base_indent))
+    }
 }
 
-fn apply_change_set(json_value: &mut Value, change_set: ChangeSet) {
-    for change in change_set.changes {
-        apply_change(json_value, change);
+/// Applies the changes to the text itself, so everything they don't touch keeps its formatting.
+pub fn apply_change_sets(
+    json_str: &str,
+    change_sets: Vec<ChangeSet>,
+    key_order: KeyOrder,
+) -> String {
+    serde_json::from_str::<Value>(json_str).expect("Invalid JSON");
+
+    let style = JsonStyle::detect(json_str);
+    let mut text = json_str.to_string();
+
+    for change_set in change_sets {
+        for change in change_set.changes {
+            text = apply_change(&text, change, &style, key_order);
+        }
     }
+
+    text
 }
 
-fn apply_change(json_value: &mut Value, change: Change) {
-    let keys: Vec<&str> = change.path.split('.').collect();
-    let mut current = json_value;
+fn apply_change(text: &str, change: Change, style: &JsonStyle, key_order: KeyOrder) -> String {
+    let keys = parse_json_pointer(&change.path);
+    let (final_key, parent_keys) = keys.split_last().unwrap();
+    let root = json_spans::parse(text);
+    let mut parent = &root;
 
-    for key in &keys[..keys.len() - 1] {
-        current = current
-            .as_object_mut()
-            .expect("Intermediate value is not an object")
-            .entry(*key)
-            .or_insert(Value::Object(Map::new()));
+    for (depth, key) in parent_keys.iter().enumerate() {
+        let child = match &parent.kind {
+            JsonNodeKind::Object(members) => members
+                .iter()
+                .find(|member| &member.key == key)
+                .map(|member| &member.value),
+            JsonNodeKind::Array(items) => Some(
+                key.parse::<usize>()
+                    .ok()
+                    .and_then(|index| items.get(index))
+                    .expect("Intermediate array index is out of bounds"),
+            ),
+            JsonNodeKind::Scalar => panic!("Intermediate value is not an object"),
+        };
+
+        if let Some(child) = child {
+            parent = child;
+            continue;
+        }
+
+        // Missing intermediate objects get created along with the inserted value.
+        let inserted_value = match change.change_type {
+            ChangeType::Add | ChangeType::Update => change.value.clone(),
+            ChangeType::ArrayAppend => change.value.clone().map(|value| Value::Array(vec![value])),
+            _ => None,
+        };
+        let Some(inserted_value) = inserted_value else {
+            return text.to_string();
+        };
+
+        let nested_value = parent_keys[depth + 1..]
+            .iter()
+            .chain([final_key])
+            .rev()
+            .fold(inserted_value, |value, key| {
+                Value::Object(Map::from_iter([(key.clone(), value)]))
+            });
+
+        return insert_member(
+            text,
+            parent,
+            &parent_keys[..depth],
+            key,
+            &nested_value,
+            style,
+            key_order,
+        );
     }
 
-    let final_key = keys.last().unwrap();
+    let (entry_index, target) = match &parent.kind {
+        JsonNodeKind::Object(members) => {
+            match members.iter().position(|member| &member.key == final_key) {
+                Some(member_index) => (member_index, &members[member_index].value),
+                None => {
+                    return insert_missing_member(
+                        text,
+                        parent,
+                        parent_keys,
+                        final_key,
+                        change,
+                        style,
+                        key_order,
+                    )
+                }
+            }
+        }
+        // Items of an array are addressed by index, like `/exports/./0`; only existing ones change.
+        JsonNodeKind::Array(items) => match final_key.parse::<usize>() {
+            Ok(item_index) if item_index < items.len() => (item_index, &items[item_index]),
+            _ => return text.to_string(),
+        },
+        JsonNodeKind::Scalar => panic!("Target is not an object"),
+    };
+
+    match (change.change_type, change.value) {
+        (ChangeType::Add | ChangeType::Update, Some(value)) => {
+            replace_value(text, target, &value, style)
+        }
+        (ChangeType::Remove, _) => remove_entry(text, parent, entry_index),
+        (ChangeType::ArrayAppend, Some(value)) => {
+            let JsonNodeKind::Array(items) = &target.kind else {
+                panic!("Target is not an array");
+            };
 
-    match change.change_type {
-        ChangeType::Add | ChangeType::Update => {
-            if let Some(val) = change.value {
-                // This is causing issues with the order of the keys in the JSON
-                current
-                    .as_object_mut()
-                    .expect("Target is not an object")
-                    .insert(final_key.to_string(), Value::String(val));
+            insert_entry(text, target, items.len(), style, |indent| {
+                style.serialize(&value, indent)
+            })
+        }
+        (ChangeType::ArrayRemove, Some(value)) => {
+            let JsonNodeKind::Array(items) = &target.kind else {
+                panic!("Target is not an array");
+            };
+
+            match items
+                .iter()
+                .position(|item| read_value(text, item) == value)
+            {
+                // Removing shifts the spans, so the rest of the matches are searched for again.
+                Some(item_index) => apply_change(
+                    &remove_entry(text, target, item_index),
+                    Change {
+                        path: change.path,
+                        change_type: ChangeType::ArrayRemove,
+                        value: Some(value),
+                    },
+                    style,
+                    key_order,
+                ),
+                None => text.to_string(),
             }
         }
-        ChangeType::Remove => {
-            current
-                .as_object_mut()
-                .expect("Target is not an object")
-                .shift_remove(*final_key);
+        (ChangeType::MoveKey(destination), _) => {
+            let value = read_value(text, target);
+
+            apply_change(
+                &remove_entry(text, parent, entry_index),
+                Change {
+                    path: destination,
+                    change_type: ChangeType::Add,
+                    value: Some(value),
+                },
+                style,
+                key_order,
+            )
+        }
+        (ChangeType::RenameKey(new_key), _) => {
+            let JsonNodeKind::Object(members) = &parent.kind else {
+                panic!("Target is not an object member");
+            };
+
+            if !members.iter().any(|member| member.key == new_key) {
+                let member = &members[entry_index];
+
+                return splice(
+                    text,
+                    member.key_start,
+                    member.key_end,
+                    &serde_json::to_string(&new_key).expect("Failed to serialize JSON"),
+                );
+            }
+
+            let mut new_keys: Vec<&str> = parent_keys.iter().map(String::as_str).collect();
+            new_keys.push(&new_key);
+            let existing_member = members.iter().find(|member| member.key == new_key).unwrap();
+
+            // Objects on both sides are merged key by key, the renamed one winning on conflicts.
+            let additions = match (
+                read_value(text, target),
+                read_value(text, &existing_member.value),
+            ) {
+                (Value::Object(renamed), Value::Object(_)) => renamed
+                    .into_iter()
+                    .map(|(key, value)| {
+                        let mut keys = new_keys.clone();
+                        keys.push(&key);
+                        (to_json_pointer(&keys), value)
+                    })
+                    .collect(),
+                (renamed, _) => vec![(to_json_pointer(&new_keys), renamed)],
+            };
+
+            additions.into_iter().fold(
+                remove_entry(text, parent, entry_index),
+                |text, (path, value)| {
+                    apply_change(
+                        &text,
+                        Change {
+                            path,
+                            change_type: ChangeType::Add,
+                            value: Some(value),
+                        },
+                        style,
+                        key_order,
+                    )
+                },
+            )
         }
+        _ => text.to_string(),
+    }
+}
+
+fn insert_missing_member(
+    text: &str,
+    parent: &JsonNode,
+    parent_keys: &[String],
+    final_key: &str,
+    change: Change,
+    style: &JsonStyle,
+    key_order: KeyOrder,
+) -> String {
+    match (change.change_type, change.value) {
+        (ChangeType::Add | ChangeType::Update, Some(value)) => insert_member(
+            text,
+            parent,
+            parent_keys,
+            final_key,
+            &value,
+            style,
+            key_order,
+        ),
+        (ChangeType::ArrayAppend, Some(value)) => insert_member(
+            text,
+            parent,
+            parent_keys,
+            final_key,
+            &Value::Array(vec![value]),
+            style,
+            key_order,
+        ),
+        _ => text.to_string(),
+    }
+}
+
+fn insert_member(
+    text: &str,
+    object: &JsonNode,
+    parent_keys: &[String],
+    key: &str,
+    value: &Value,
+    style: &JsonStyle,
+    key_order: KeyOrder,
+) -> String {
+    let JsonNodeKind::Object(members) = &object.kind else {
+        panic!("Target is not an object");
+    };
+
+    let sibling_keys: Vec<&str> = members.iter().map(|member| member.key.as_str()).collect();
+    let index = key_order.get_insert_index(parent_keys, &sibling_keys, key);
+    let key = serde_json::to_string(key).expect("Failed to serialize JSON");
+
+    insert_entry(text, object, index, style, |indent| {
+        format!("{}: {}", key, style.serialize(value, indent))
+    })
+}
+
+/// Inserts an object member or array item before the entry at `index`, following the layout of
+/// its siblings: one per line, or all on a single line.
+fn insert_entry(
+    text: &str,
+    container: &JsonNode,
+    index: usize,
+    style: &JsonStyle,
+    entry: impl Fn(Option<&str>) -> String,
+) -> String {
+    let spans = get_entry_spans(container);
+    let newline = style.newline;
+
+    let Some(last_span) = spans.last() else {
+        let closing_indent = get_line_indent(text, container.start);
+        let indent = format!("{}{}", closing_indent, style.indent);
+
+        return splice(
+            text,
+            container.start + 1,
+            container.end - 1,
+            &format!(
+                "{}{}{}{}{}",
+                newline,
+                indent,
+                entry(Some(&indent)),
+                newline,
+                closing_indent
+            ),
+        );
+    };
+
+    if container.is_single_line(text) {
+        return match spans.get(index) {
+            Some((start, _)) => splice(text, *start, *start, &format!("{}, ", entry(None))),
+            None => splice(
+                text,
+                last_span.1,
+                last_span.1,
+                &format!(", {}", entry(None)),
+            ),
+        };
+    }
+
+    let indent = get_line_indent(text, spans[0].0);
+
+    match spans.get(index) {
+        Some((start, _)) => splice(
+            text,
+            *start,
+            *start,
+            &format!("{},{}{}", entry(Some(&indent)), newline, indent),
+        ),
+        None => splice(
+            text,
+            last_span.1,
+            last_span.1,
+            &format!(",{}{}{}", newline, indent, entry(Some(&indent))),
+        ),
+    }
+}
+
+/// Removes an object member or array item along with the comma and whitespace separating it.
+fn remove_entry(text: &str, container: &JsonNode, index: usize) -> String {
+    let spans = get_entry_spans(container);
+
+    let (start, end) = if spans.len() == 1 {
+        (container.start + 1, container.end - 1)
+    } else if index + 1 < spans.len() {
+        (spans[index].0, spans[index + 1].0)
+    } else {
+        (spans[index - 1].1, spans[index].1)
+    };
+
+    splice(text, start, end, "")
+}
+
+fn replace_value(text: &str, node: &JsonNode, value: &Value, style: &JsonStyle) -> String {
+    let is_inline = match &node.kind {
+        JsonNodeKind::Object(members) => !members.is_empty() && node.is_single_line(text),
+        JsonNodeKind::Array(items) => !items.is_empty() && node.is_single_line(text),
+        JsonNodeKind::Scalar => false,
     };
+    let indent = get_line_indent(text, node.start);
+    let value = style.serialize(value, (!is_inline).then_some(indent.as_str()));
+
+    splice(text, node.start, node.end, &value)
+}
+
+fn get_entry_spans(container: &JsonNode) -> Vec<(usize, usize)> {
+    match &container.kind {
+        JsonNodeKind::Object(members) => members
+            .iter()
+            .map(|member| (member.key_start, member.value.end))
+            .collect(),
+        JsonNodeKind::Array(items) => items.iter().map(|item| (item.start, item.end)).collect(),
+        JsonNodeKind::Scalar => vec![],
+    }
+}
+
+fn get_line_indent(text: &str, position: usize) -> String {
+    let line_start = text[..position].rfind('\n').map_or(0, |index| index + 1);
+
+    text[line_start..]
+        .chars()
+        .take_while(|c| *c == ' ' || *c == '\t')
+        .collect()
+}
+
+fn read_value(text: &str, node: &JsonNode) -> Value {
+    serde_json::from_str(&text[node.start..node.end]).expect("Invalid JSON")
+}
+
+fn splice(text: &str, start: usize, end: usize, replacement: &str) -> String {
+    format!("{}{}{}", &text[..start], replacement, &text[end..])
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+
+    use super::*;
+
+    fn apply(json_str: &str, path: &str, change_type: ChangeType, value: Option<Value>) -> String {
+        apply_change_sets(
+            json_str,
+            vec![ChangeSet {
+                description: String::new(),
+                json_path: path.to_string(),
+                changes: vec![Change {
+                    path: path.to_string(),
+                    change_type,
+                    value,
+                }],
+            }],
+            KeyOrder::Append,
+        )
+    }
+
+    #[test]
+    fn array_remove_drops_every_equal_item_and_keeps_the_formatting() {
+        let json_str = "{\r\n\t\"files\": [\r\n\t\t\"dist\",\r\n\t\t\"src\",\r\n\t\t{ \"a\": 1 },\r\n\t\t\"src\"\r\n\t],\r\n\t\"keywords\": [\"a\", \"b\"]\r\n}";
+
+        assert_eq!(
+            apply(json_str, "/files", ChangeType::ArrayRemove, Some(json!("src"))),
+            "{\r\n\t\"files\": [\r\n\t\t\"dist\",\r\n\t\t{ \"a\": 1 }\r\n\t],\r\n\t\"keywords\": [\"a\", \"b\"]\r\n}"
+        );
+        assert_eq!(
+            apply(json_str, "/keywords", ChangeType::ArrayRemove, Some(json!("b"))),
+            "{\r\n\t\"files\": [\r\n\t\t\"dist\",\r\n\t\t\"src\",\r\n\t\t{ \"a\": 1 },\r\n\t\t\"src\"\r\n\t],\r\n\t\"keywords\": [\"a\"]\r\n}"
+        );
+    }
+
+    #[test]
+    fn rename_key_keeps_the_position_and_the_formatting() {
+        let json_str = "{\n    \"name\": \"test\",\n    \"module\":   \"./index.mjs\",\n    \"main\": \"./index.js\"\n}\n";
+
+        assert_eq!(
+            apply(
+                json_str,
+                "/module",
+                ChangeType::RenameKey("browser".to_string()),
+                None
+            ),
+            "{\n    \"name\": \"test\",\n    \"browser\":   \"./index.mjs\",\n    \"main\": \"./index.js\"\n}\n"
+        );
+    }
+
+    #[test]
+    fn rename_key_onto_an_existing_key_merges_into_it() {
+        let json_str = "{\n  \"name\": \"test\",\n  \"dependencies\": {\n    \"a\": \"1.0.0\",\n    \"b\": \"1.0.0\"\n  },\n  \"deps\": {\n    \"b\": \"2.0.0\",\n    \"c\": \"1.0.0\"\n  },\n  \"main\": \"./a.js\",\n  \"module\": \"./b.js\"\n}\n";
+
+        assert_eq!(
+            apply(
+                json_str,
+                "/deps",
+                ChangeType::RenameKey("dependencies".to_string()),
+                None
+            ),
+            "{\n  \"name\": \"test\",\n  \"dependencies\": {\n    \"a\": \"1.0.0\",\n    \"b\": \"2.0.0\",\n    \"c\": \"1.0.0\"\n  },\n  \"main\": \"./a.js\",\n  \"module\": \"./b.js\"\n}\n"
+        );
+        assert_eq!(
+            apply(
+                json_str,
+                "/module",
+                ChangeType::RenameKey("main".to_string()),
+                None
+            ),
+            "{\n  \"name\": \"test\",\n  \"dependencies\": {\n    \"a\": \"1.0.0\",\n    \"b\": \"1.0.0\"\n  },\n  \"deps\": {\n    \"b\": \"2.0.0\",\n    \"c\": \"1.0.0\"\n  },\n  \"main\": \"./b.js\"\n}\n"
+        );
+    }
 }
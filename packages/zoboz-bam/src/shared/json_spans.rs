@@ -0,0 +1,176 @@
+/// A JSON value along with the byte range it covers in the source text.
+pub struct JsonNode {
+    pub start: usize,
+    pub end: usize,
+    pub kind: JsonNodeKind,
+}
+
+pub enum JsonNodeKind {
+    Object(Vec<JsonMember>),
+    Array(Vec<JsonNode>),
+    Scalar,
+}
+
+pub struct JsonMember {
+    pub key: String,
+    pub key_start: usize,
+    pub key_end: usize,
+    pub value: JsonNode,
+}
+
+impl JsonNode {
+    pub fn is_single_line(&self, text: &str) -> bool {
+        !text[self.start..self.end].contains('\n')
+    }
+
+    /// Where the value at the given keys is written, at its key when it has one. Keys that don't
+    /// exist fall back to the closest ancestor that does.
+    pub fn find_offset(&self, keys: &[String]) -> usize {
+        let Some((key, rest)) = keys.split_first() else {
+            return self.start;
+        };
+
+        match &self.kind {
+            JsonNodeKind::Object(members) => {
+                match members.iter().rev().find(|member| &member.key == key) {
+                    Some(member) if rest.is_empty() => member.key_start,
+                    Some(member) => member.value.find_offset(rest),
+                    None => self.start,
+                }
+            }
+            JsonNodeKind::Array(items) => {
+                match key.parse::<usize>().ok().and_then(|index| items.get(index)) {
+                    Some(item) => item.find_offset(rest),
+                    None => self.start,
+                }
+            }
+            JsonNodeKind::Scalar => self.start,
+        }
+    }
+}
+
+/// Locates every value of an already validated JSON text, so edits can be made in place.
+pub fn parse(text: &str) -> JsonNode {
+    let mut parser = Parser {
+        text,
+        bytes: text.as_bytes(),
+        position: 0,
+    };
+
+    parser.skip_whitespace();
+    parser.parse_value()
+}
+
+struct Parser<'a> {
+    text: &'a str,
+    bytes: &'a [u8],
+    position: usize,
+}
+
+impl Parser<'_> {
+    fn parse_value(&mut self) -> JsonNode {
+        let start = self.position;
+
+        let kind = match self.bytes.get(self.position) {
+            Some(b'{') => JsonNodeKind::Object(self.parse_members()),
+            Some(b'[') => JsonNodeKind::Array(self.parse_items()),
+            Some(b'"') => {
+                self.skip_string();
+                JsonNodeKind::Scalar
+            }
+            _ => {
+                while self.position < self.bytes.len()
+                    && !matches!(self.bytes[self.position], b',' | b'}' | b']')
+                    && !self.bytes[self.position].is_ascii_whitespace()
+                {
+                    self.position += 1;
+                }
+                JsonNodeKind::Scalar
+            }
+        };
+
+        JsonNode {
+            start,
+            end: self.position,
+            kind,
+        }
+    }
+
+    fn parse_members(&mut self) -> Vec<JsonMember> {
+        let mut members = vec![];
+        self.position += 1;
+
+        loop {
+            self.skip_whitespace();
+
+            match self.bytes.get(self.position) {
+                Some(b'}') | None => break,
+                Some(b',') => self.position += 1,
+                _ => {
+                    let key_start = self.position;
+                    self.skip_string();
+                    let key_end = self.position;
+                    let key = serde_json::from_str(&self.text[key_start..key_end])
+                        .expect("Invalid JSON key");
+
+                    self.skip_whitespace();
+                    self.position += 1; // The `:` between a key and its value.
+                    self.skip_whitespace();
+
+                    members.push(JsonMember {
+                        key,
+                        key_start,
+                        key_end,
+                        value: self.parse_value(),
+                    });
+                }
+            }
+        }
+
+        self.position += 1;
+        members
+    }
+
+    fn parse_items(&mut self) -> Vec<JsonNode> {
+        let mut items = vec![];
+        self.position += 1;
+
+        loop {
+            self.skip_whitespace();
+
+            match self.bytes.get(self.position) {
+                Some(b']') | None => break,
+                Some(b',') => self.position += 1,
+                _ => items.push(self.parse_value()),
+            }
+        }
+
+        self.position += 1;
+        items
+    }
+
+    fn skip_string(&mut self) {
+        self.position += 1;
+
+        while let Some(byte) = self.bytes.get(self.position) {
+            self.position += match byte {
+                b'\\' => 2,
+                b'"' => {
+                    self.position += 1;
+                    return;
+                }
+                _ => 1,
+            };
+        }
+    }
+
+    fn skip_whitespace(&mut self) {
+        while self
+            .bytes
+            .get(self.position)
+            .is_some_and(|byte| byte.is_ascii_whitespace())
+        {
+            self.position += 1;
+        }
+    }
+}
@@ -1,5 +1,16 @@
+pub mod glob_matcher;
 pub mod json_editor;
+pub mod json_spans;
+pub mod module_format;
+pub mod npm_packlist;
 pub mod package_json_reader;
+pub mod reporter;
+pub mod semver_range;
+pub mod side_effects_scanner;
+pub mod specifiers_scanner;
 pub mod tsconfig_reader;
+pub mod type_marker;
+pub mod unified_diff;
 pub mod utils;
 pub mod value_objects;
+pub mod workspaces;
@@ -0,0 +1,126 @@
+use std::path::{Path, PathBuf};
+
+use lazy_static::lazy_static;
+
+lazy_static! {
+    // NOTE: Dynamic `import()` and `import.meta` are left out on purpose, CommonJS can use the former too.
+    static ref RE_ESM_SYNTAX: regex::Regex =
+        regex::Regex::new(r#"(?m)^\s*(import(\s+[\w{*'"]|\s*[{*'"])|export(\s+[\w{*]|\s*[{*]))"#).unwrap();
+    static ref RE_CJS_SYNTAX: regex::Regex =
+        regex::Regex::new(r#"\brequire\s*\(|\bmodule\.exports\b|\bexports\.[\w$]+\s*="#).unwrap();
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ModuleFormat {
+    Esm,
+    Cjs,
+}
+
+impl ModuleFormat {
+    pub fn value(&self) -> &str {
+        match self {
+            ModuleFormat::Esm => "ESM",
+            ModuleFormat::Cjs => "CJS",
+        }
+    }
+
+    /// The package.json `type` Node.js loads `.js` files in this format with.
+    pub fn get_type_field(&self) -> &'static str {
+        match self {
+            ModuleFormat::Esm => "module",
+            ModuleFormat::Cjs => "commonjs",
+        }
+    }
+}
+
+/// The format Node.js loads a JS file as: the extension wins, then the nearest package.json
+/// `type`, and without one, the syntax found in the file.
+pub fn detect_runtime_format(file_path: &Path) -> ModuleFormat {
+    let file_name = file_path.to_string_lossy();
+
+    if file_name.ends_with(".mjs") {
+        return ModuleFormat::Esm;
+    }
+
+    if file_name.ends_with(".cjs") {
+        return ModuleFormat::Cjs;
+    }
+
+    match get_nearest_type_field(file_path).as_deref() {
+        Some("module") => ModuleFormat::Esm,
+        Some("commonjs") => ModuleFormat::Cjs,
+        _ => {
+            let file_content = std::fs::read_to_string(file_path).unwrap_or_default();
+            detect_syntax_format(&file_content)
+        }
+    }
+}
+
+/// The format TypeScript assumes a declaration file describes, which never looks at its syntax.
+pub fn detect_types_format(file_path: &Path) -> ModuleFormat {
+    let file_name = file_path.to_string_lossy();
+
+    if file_name.ends_with(".d.mts") || file_name.ends_with(".mts") {
+        return ModuleFormat::Esm;
+    }
+
+    if file_name.ends_with(".d.cts") || file_name.ends_with(".cts") {
+        return ModuleFormat::Cjs;
+    }
+
+    match get_nearest_type_field(file_path).as_deref() {
+        Some("module") => ModuleFormat::Esm,
+        _ => ModuleFormat::Cjs,
+    }
+}
+
+pub fn detect_syntax_format(file_content: &str) -> ModuleFormat {
+    if RE_ESM_SYNTAX.is_match(file_content) {
+        ModuleFormat::Esm
+    } else {
+        ModuleFormat::Cjs
+    }
+}
+
+/// Like `detect_syntax_format`, but `None` for files that read the same in both formats, e.g.
+/// ones that only assign to globals.
+pub fn detect_explicit_syntax_format(file_content: &str) -> Option<ModuleFormat> {
+    if RE_ESM_SYNTAX.is_match(file_content) {
+        Some(ModuleFormat::Esm)
+    } else if RE_CJS_SYNTAX.is_match(file_content) {
+        Some(ModuleFormat::Cjs)
+    } else {
+        None
+    }
+}
+
+/// Reads `type` from the closest package.json above the file; a package.json without it counts as unset.
+pub fn get_nearest_type_field(file_path: &Path) -> Option<String> {
+    let package_json_path = file_path
+        .ancestors()
+        .skip(1)
+        .map(|dir| dir.join("package.json"))
+        .find(|package_json_path| package_json_path.is_file())?;
+
+    let package_json_content = std::fs::read_to_string(package_json_path).ok()?;
+    let package_json: serde_json::Value = serde_json::from_str(&package_json_content).ok()?;
+
+    package_json
+        .get("type")
+        .and_then(|type_field| type_field.as_str())
+        .map(|type_field| type_field.to_string())
+}
+
+/// The CJS build of an ESM file sits at the same path, with either a `.js` or a `.cjs` extension.
+pub fn find_cjs_counterpart(
+    esm_output_dir: &Path,
+    cjs_output_dir: &Path,
+    esm_file_path: &Path,
+) -> Option<PathBuf> {
+    let relative_path = esm_file_path.strip_prefix(esm_output_dir).ok()?;
+
+    ["js", "cjs"]
+        .iter()
+        .map(|extension| cjs_output_dir.join(relative_path.with_extension(extension)))
+        .find(|cjs_file_path| cjs_file_path.is_file())
+}
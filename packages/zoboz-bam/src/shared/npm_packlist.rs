@@ -0,0 +1,181 @@
+use std::collections::HashMap;
+use std::path::Path;
+
+use super::glob_matcher::{glob_to_regex, IgnoreRule};
+use super::package_json_reader::PackageJson;
+use super::utils;
+use super::value_objects::AbsolutePackageDir;
+
+// Mirrors npm-packlist: these never make it into the tarball, no matter what `files` says.
+const ALWAYS_IGNORED: &[&str] = &[
+    ".npmignore",
+    ".gitignore",
+    ".git",
+    ".svn",
+    ".hg",
+    "CVS",
+    "node_modules",
+    "/.lock-wscript",
+    "/.wafpickle-*",
+    "/build/config.gypi",
+    "npm-debug.log",
+    ".npmrc",
+    ".*.swp",
+    ".DS_Store",
+    "._*",
+    "*.orig",
+    "/package-lock.json",
+    "/yarn.lock",
+    "/pnpm-lock.yaml",
+    "/bun.lockb",
+    "/archived-packages/",
+];
+
+const ALWAYS_INCLUDED_PREFIXES: &[&str] = &["readme", "license", "licence"];
+
+/// Works out the files `npm pack` would publish, as sorted `/` separated paths relative to the package.
+pub fn list_packed_files(
+    package_dir: &AbsolutePackageDir,
+    package_json: &PackageJson,
+) -> Vec<String> {
+    let always_ignored: Vec<IgnoreRule> = ALWAYS_IGNORED
+        .iter()
+        .filter_map(|line| IgnoreRule::parse(line))
+        .collect();
+
+    let always_included: Vec<String> = package_json
+        .main
+        .iter()
+        .cloned()
+        .chain(
+            package_json
+                .get_bin_entries()
+                .into_iter()
+                .map(|(_, bin_path)| bin_path),
+        )
+        .map(|path| normalize(&path))
+        .collect();
+
+    let mut ignore_rules_by_dir: HashMap<String, Vec<IgnoreRule>> = HashMap::new();
+
+    utils::list_relative_files(package_dir.value(), &[".git", "node_modules"])
+        .into_iter()
+        .filter(|relative_path| {
+            if is_always_included(relative_path, &always_included) {
+                return true;
+            }
+
+            if always_ignored
+                .iter()
+                .any(|rule| rule.is_match(relative_path))
+            {
+                return false;
+            }
+
+            if let Some(files) = &package_json.files {
+                if !is_listed_in_files(relative_path, files) {
+                    return false;
+                }
+            }
+
+            !is_ignored(
+                package_dir.value(),
+                relative_path,
+                // With `files` in place, npm only respects the ignore files of nested directories.
+                package_json.files.is_none(),
+                &mut ignore_rules_by_dir,
+            )
+        })
+        .collect()
+}
+
+fn normalize(path: &str) -> String {
+    path.trim_start_matches("./")
+        .trim_end_matches('/')
+        .replace('\\', "/")
+}
+
+fn is_always_included(relative_path: &str, always_included: &[String]) -> bool {
+    if relative_path == "package.json" || always_included.iter().any(|path| path == relative_path) {
+        return true;
+    }
+
+    let file_name = relative_path.to_lowercase();
+
+    !file_name.contains('/')
+        && ALWAYS_INCLUDED_PREFIXES
+            .iter()
+            .any(|prefix| file_name.starts_with(prefix))
+}
+
+fn is_listed_in_files(relative_path: &str, files: &[String]) -> bool {
+    let mut is_listed = false;
+
+    for entry in files {
+        let (negated, entry) = match entry.strip_prefix('!') {
+            Some(entry) => (true, entry),
+            None => (false, entry.as_str()),
+        };
+
+        // Like in .gitignore, only entries with a slash are anchored at the package root.
+        let entry = entry.trim_end_matches('/');
+        let pattern = if entry.contains('/') {
+            glob_to_regex(normalize(entry).trim_start_matches('/'))
+        } else {
+            glob_to_regex(&format!("**/{}", entry))
+        };
+        let segments: Vec<&str> = relative_path.split('/').collect();
+
+        // Listing a directory lists everything inside it.
+        let matches = (1..=segments.len())
+            .map(|length| segments[..length].join("/"))
+            .any(|path| pattern.is_match(&path));
+
+        if matches {
+            is_listed = !negated;
+        }
+    }
+
+    is_listed
+}
+
+fn is_ignored(
+    package_dir: &Path,
+    relative_path: &str,
+    respects_root_ignore_file: bool,
+    ignore_rules_by_dir: &mut HashMap<String, Vec<IgnoreRule>>,
+) -> bool {
+    let segments: Vec<&str> = relative_path.split('/').collect();
+    let mut is_ignored = false;
+
+    for depth in 0..segments.len() {
+        if depth == 0 && !respects_root_ignore_file {
+            continue;
+        }
+
+        let dir = segments[..depth].join("/");
+        let path_in_dir = segments[depth..].join("/");
+
+        let rules = ignore_rules_by_dir
+            .entry(dir.clone())
+            .or_insert_with(|| read_ignore_rules(&package_dir.join(&dir)));
+
+        // Deeper ignore files, and later lines within one, override what came before.
+        for rule in rules.iter() {
+            if rule.is_match(&path_in_dir) {
+                is_ignored = !rule.is_negated();
+            }
+        }
+    }
+
+    is_ignored
+}
+
+/// `.npmignore` takes over `.gitignore` when both exist in the same directory.
+fn read_ignore_rules(dir: &Path) -> Vec<IgnoreRule> {
+    let content = std::fs::read_to_string(dir.join(".npmignore"))
+        .or_else(|_| std::fs::read_to_string(dir.join(".gitignore")))
+        .unwrap_or_default();
+
+    IgnoreRule::parse_all(&content)
+}
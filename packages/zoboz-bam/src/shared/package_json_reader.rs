@@ -1,16 +1,120 @@
+use std::collections::HashMap;
+
+use super::json_editor::to_json_pointer;
 use super::value_objects::AbsolutePackageDir;
 
+/// Fields are read leniently: one of the wrong type reads as missing instead of emptying the
+/// whole manifest, and a dependency with a non-string range is left out of its map.
 #[derive(serde::Deserialize, Default)]
 pub struct PackageJson {
-    #[serde(rename = "type")]
+    #[serde(default, deserialize_with = "lenient")]
+    pub name: Option<String>,
+    #[serde(default, deserialize_with = "lenient")]
+    pub version: Option<String>,
+    #[serde(default, deserialize_with = "lenient", rename = "type")]
     pub type_field: Option<String>,
+    #[serde(default, deserialize_with = "lenient_dependencies")]
+    pub dependencies: HashMap<String, String>,
+    #[serde(
+        default,
+        deserialize_with = "lenient_dependencies",
+        rename = "devDependencies"
+    )]
+    pub dev_dependencies: HashMap<String, String>,
+    #[serde(
+        default,
+        deserialize_with = "lenient_dependencies",
+        rename = "peerDependencies"
+    )]
+    pub peer_dependencies: HashMap<String, String>,
+    #[serde(
+        default,
+        deserialize_with = "lenient_dependencies",
+        rename = "optionalDependencies"
+    )]
+    pub optional_dependencies: HashMap<String, String>,
+    #[serde(default, deserialize_with = "lenient", rename = "peerDependenciesMeta")]
+    pub peer_dependencies_meta: HashMap<String, serde_json::Value>,
+    pub exports: Option<serde_json::Value>,
+    #[serde(default, deserialize_with = "lenient")]
+    pub main: Option<String>,
+    #[serde(default, deserialize_with = "lenient")]
+    pub module: Option<String>,
+    #[serde(default, deserialize_with = "lenient")]
+    pub types: Option<String>,
+    #[serde(default, deserialize_with = "lenient")]
+    pub typings: Option<String>,
+    pub bin: Option<serde_json::Value>,
+    #[serde(default, deserialize_with = "lenient")]
+    pub files: Option<Vec<String>>,
+    #[serde(rename = "sideEffects")]
+    pub side_effects: Option<serde_json::Value>,
+    pub workspaces: Option<serde_json::Value>,
+    pub zoboz: Option<serde_json::Value>,
+}
+
+impl PackageJson {
+    /// `bin` is either a single path named after the package, or an object of command names to paths.
+    pub fn get_bin_entries(&self) -> Vec<(String, String)> {
+        match &self.bin {
+            Some(serde_json::Value::String(bin_path)) => {
+                let command_name = self.name.as_deref().unwrap_or_default();
+                let command_name = command_name.rsplit('/').next().unwrap_or_default();
+                vec![(command_name.to_string(), bin_path.clone())]
+            }
+            Some(serde_json::Value::Object(entries)) => entries
+                .iter()
+                .filter_map(|(command_name, bin_path)| {
+                    bin_path
+                        .as_str()
+                        .map(|bin_path| (command_name.clone(), bin_path.to_string()))
+                })
+                .collect(),
+            _ => vec![],
+        }
+    }
+
+    /// A string `bin` belongs to the command named after the package, an object has one key per command.
+    pub fn get_bin_json_path(&self, command_name: &str) -> String {
+        match &self.bin {
+            Some(serde_json::Value::Object(_)) => to_json_pointer(&["bin", command_name]),
+            _ => "/bin".to_string(),
+        }
+    }
+}
+
+fn lenient<'de, D, T>(deserializer: D) -> Result<T, D::Error>
+where
+    D: serde::Deserializer<'de>,
+    T: serde::de::DeserializeOwned + Default,
+{
+    let value: serde_json::Value = serde::Deserialize::deserialize(deserializer)?;
+
+    Ok(serde_json::from_value(value).unwrap_or_default())
+}
+
+fn lenient_dependencies<'de, D>(deserializer: D) -> Result<HashMap<String, String>, D::Error>
+where
+    D: serde::Deserializer<'de>,
+{
+    let value: serde_json::Value = serde::Deserialize::deserialize(deserializer)?;
+
+    Ok(match value {
+        serde_json::Value::Object(dependencies) => dependencies
+            .into_iter()
+            .filter_map(|(name, range)| match range {
+                serde_json::Value::String(range) => Some((name, range)),
+                _ => None,
+            })
+            .collect(),
+        _ => HashMap::new(),
+    })
 }
 
 pub fn get_package_json_string(package_dir: &AbsolutePackageDir) -> String {
     let package_json_path = package_dir.value().join("package.json");
-    let package_json_content = std::fs::read_to_string(package_json_path).unwrap_or_default();
 
-    package_json_content
+    std::fs::read_to_string(package_json_path).unwrap_or_default()
 }
 
 pub fn get_package_json(package_dir: &AbsolutePackageDir) -> PackageJson {
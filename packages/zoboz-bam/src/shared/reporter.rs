@@ -0,0 +1,203 @@
+use std::{fs, path::PathBuf};
+
+use serde_json::{json, Value};
+
+#[derive(Clone, Copy, PartialEq, Eq)]
+pub enum Level {
+    Error,
+    Warning,
+    Note,
+}
+
+/// Something worth pointing at in a file, so CI can show it inline.
+pub struct Finding {
+    pub rule_id: String,
+    pub level: Level,
+    pub message: String,
+    pub file_path: PathBuf,
+    pub line: usize,
+    pub column: usize,
+}
+
+#[derive(Clone, Copy, PartialEq, Eq)]
+pub enum Reporter {
+    Sarif,
+    Github,
+}
+
+impl Reporter {
+    pub fn new(reporter: &str) -> Result<Self, String> {
+        match reporter {
+            "sarif" => Ok(Reporter::Sarif),
+            "github" => Ok(Reporter::Github),
+            _ => Err(format!(
+                "Invalid reporter '{}'; use `sarif` or `github`",
+                reporter
+            )),
+        }
+    }
+
+    pub fn render(&self, findings: &[Finding]) -> String {
+        match self {
+            Reporter::Sarif => render_sarif(findings),
+            Reporter::Github => findings
+                .iter()
+                .map(render_github_command)
+                .collect::<Vec<_>>()
+                .join("\n"),
+        }
+    }
+
+    pub fn write(&self, findings: &[Finding], report_file: &str) -> Result<(), String> {
+        fs::write(report_file, self.render(findings))
+            .map_err(|e| format!("Failed to write {}: {}", report_file, e))
+    }
+
+    /// The reporter that renders to the console; a report written to a file leaves the console
+    /// to the usual output.
+    pub fn for_console(reporter: Option<Self>, report_file: Option<&str>) -> Option<Self> {
+        reporter.filter(|_| report_file.is_none())
+    }
+
+    /// Writes the report file when asked to, and prints the findings: as the report when it
+    /// isn't written to a file, as `path:line:col: message` lines otherwise.
+    pub fn emit(
+        reporter: Option<Self>,
+        findings: &[Finding],
+        report_file: Option<&str>,
+    ) -> Result<(), String> {
+        if let (Some(reporter), Some(report_file)) = (reporter, report_file) {
+            reporter.write(findings, report_file)?;
+        }
+
+        let output = match Reporter::for_console(reporter, report_file) {
+            Some(reporter) => reporter.render(findings),
+            None => findings
+                .iter()
+                .map(|finding| {
+                    format!(
+                        "{}:{}:{}: {}",
+                        finding.file_path.to_string_lossy(),
+                        finding.line,
+                        finding.column,
+                        finding.message
+                    )
+                })
+                .collect::<Vec<_>>()
+                .join("\n"),
+        };
+
+        if !output.is_empty() {
+            println!("{}", output);
+        }
+
+        Ok(())
+    }
+}
+
+/// Turns a byte offset into the 1-based line and column editors show.
+pub fn get_line_and_column(text: &str, offset: usize) -> (usize, usize) {
+    let before = &text[..offset.min(text.len())];
+    let line_start = before.rfind('\n').map_or(0, |index| index + 1);
+
+    (
+        before.matches('\n').count() + 1,
+        before[line_start..].chars().count() + 1,
+    )
+}
+
+/// A GitHub Actions workflow command, which the runner turns into an annotation on the file.
+fn render_github_command(finding: &Finding) -> String {
+    let command = match finding.level {
+        Level::Error => "error",
+        Level::Warning => "warning",
+        Level::Note => "notice",
+    };
+
+    format!(
+        "::{} file={},line={},col={},title={}::{}",
+        command,
+        escape_github_property(&finding.file_path.to_string_lossy()),
+        finding.line,
+        finding.column,
+        escape_github_property(&finding.rule_id),
+        escape_github_data(&finding.message)
+    )
+}
+
+fn escape_github_data(value: &str) -> String {
+    value
+        .replace('%', "%25")
+        .replace('\r', "%0D")
+        .replace('\n', "%0A")
+}
+
+fn escape_github_property(value: &str) -> String {
+    escape_github_data(value)
+        .replace(':', "%3A")
+        .replace(',', "%2C")
+}
+
+fn render_sarif(findings: &[Finding]) -> String {
+    let mut rule_ids: Vec<&str> = vec![];
+    for finding in findings {
+        if !rule_ids.contains(&finding.rule_id.as_str()) {
+            rule_ids.push(&finding.rule_id);
+        }
+    }
+
+    let results: Vec<Value> = findings
+        .iter()
+        .map(|finding| {
+            json!({
+                "ruleId": finding.rule_id,
+                "level": match finding.level {
+                    Level::Error => "error",
+                    Level::Warning => "warning",
+                    Level::Note => "note",
+                },
+                "message": { "text": finding.message },
+                "locations": [{
+                    "physicalLocation": {
+                        "artifactLocation": { "uri": to_file_uri(&finding.file_path) },
+                        "region": {
+                            "startLine": finding.line,
+                            "startColumn": finding.column,
+                        },
+                    },
+                }],
+            })
+        })
+        .collect();
+
+    let sarif = json!({
+        "$schema": "https://json.schemastore.org/sarif-2.1.0.json",
+        "version": "2.1.0",
+        "runs": [{
+            "tool": {
+                "driver": {
+                    "name": "zoboz-bam",
+                    "informationUri": "https://github.com/dariushalipour/zoboz",
+                    "rules": rule_ids
+                        .iter()
+                        .map(|rule_id| json!({ "id": rule_id }))
+                        .collect::<Vec<_>>(),
+                },
+            },
+            "results": results,
+        }],
+    });
+
+    serde_json::to_string_pretty(&sarif).unwrap()
+}
+
+fn to_file_uri(file_path: &std::path::Path) -> String {
+    let path = file_path.to_string_lossy().replace('\\', "/");
+    let path = path.replace('%', "%25").replace(' ', "%20");
+
+    if path.starts_with('/') {
+        format!("file://{}", path)
+    } else {
+        format!("file:///{}", path)
+    }
+}
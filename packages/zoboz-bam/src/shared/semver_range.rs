@@ -0,0 +1,539 @@
+use std::cmp::Ordering;
+
+use lazy_static::lazy_static;
+use regex::Regex;
+
+lazy_static! {
+    static ref RE_PARTIAL_VERSION: Regex = Regex::new(
+        r"^v?(\d+|[xX*])(?:\.(\d+|[xX*]))?(?:\.(\d+|[xX*]))?(?:-([0-9A-Za-z.-]+))?(?:\+[0-9A-Za-z.-]+)?$"
+    )
+    .unwrap();
+    static ref RE_OPERATOR_SPACE: Regex = Regex::new(r"(<=|>=|<|>|=|~>|~|\^)\s+").unwrap();
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+enum Identifier {
+    Numeric(u64),
+    Alphanumeric(String),
+}
+
+impl Ord for Identifier {
+    fn cmp(&self, other: &Self) -> Ordering {
+        match (self, other) {
+            (Identifier::Numeric(a), Identifier::Numeric(b)) => a.cmp(b),
+            (Identifier::Numeric(_), Identifier::Alphanumeric(_)) => Ordering::Less,
+            (Identifier::Alphanumeric(_), Identifier::Numeric(_)) => Ordering::Greater,
+            (Identifier::Alphanumeric(a), Identifier::Alphanumeric(b)) => a.cmp(b),
+        }
+    }
+}
+
+impl PartialOrd for Identifier {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Version {
+    major: u64,
+    minor: u64,
+    patch: u64,
+    prerelease: Vec<Identifier>,
+}
+
+impl Version {
+    pub fn parse(version: &str) -> Option<Self> {
+        let partial = PartialVersion::parse(version.trim().trim_start_matches('='))?;
+
+        Some(Version {
+            major: partial.major?,
+            minor: partial.minor?,
+            patch: partial.patch?,
+            prerelease: partial.prerelease,
+        })
+    }
+
+    fn new(major: u64, minor: u64, patch: u64) -> Self {
+        Version {
+            major,
+            minor,
+            patch,
+            prerelease: vec![],
+        }
+    }
+
+    /// The lowest prerelease of a version, used as an exclusive upper bound so that
+    /// `<2.0.0-0` keeps `2.0.0-beta` out just like `2.0.0`.
+    fn lowest(major: u64, minor: u64, patch: u64) -> Self {
+        Version {
+            major,
+            minor,
+            patch,
+            prerelease: vec![Identifier::Numeric(0)],
+        }
+    }
+
+    fn has_same_release(&self, other: &Version) -> bool {
+        (self.major, self.minor, self.patch) == (other.major, other.minor, other.patch)
+    }
+}
+
+impl Ord for Version {
+    fn cmp(&self, other: &Self) -> Ordering {
+        (self.major, self.minor, self.patch)
+            .cmp(&(other.major, other.minor, other.patch))
+            .then_with(
+                || match (self.prerelease.is_empty(), other.prerelease.is_empty()) {
+                    (true, true) => Ordering::Equal,
+                    (true, false) => Ordering::Greater,
+                    (false, true) => Ordering::Less,
+                    (false, false) => self.prerelease.cmp(&other.prerelease),
+                },
+            )
+    }
+}
+
+impl PartialOrd for Version {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl std::fmt::Display for Version {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}.{}.{}", self.major, self.minor, self.patch)?;
+
+        if !self.prerelease.is_empty() {
+            let prerelease: Vec<String> = self
+                .prerelease
+                .iter()
+                .map(|identifier| match identifier {
+                    Identifier::Numeric(number) => number.to_string(),
+                    Identifier::Alphanumeric(text) => text.clone(),
+                })
+                .collect();
+            write!(f, "-{}", prerelease.join("."))?;
+        }
+
+        Ok(())
+    }
+}
+
+/// A version where any missing or `x` part is left as `None`.
+struct PartialVersion {
+    major: Option<u64>,
+    minor: Option<u64>,
+    patch: Option<u64>,
+    prerelease: Vec<Identifier>,
+}
+
+impl PartialVersion {
+    fn parse(version: &str) -> Option<Self> {
+        let captures = RE_PARTIAL_VERSION.captures(version)?;
+        let get_number = |index: usize| {
+            captures
+                .get(index)
+                .and_then(|part| part.as_str().parse::<u64>().ok())
+        };
+
+        let major = get_number(1);
+        let minor = major.and(get_number(2));
+        let patch = minor.and(get_number(3));
+        let prerelease = captures
+            .get(4)
+            .filter(|_| patch.is_some())
+            .map(|prerelease| {
+                prerelease
+                    .as_str()
+                    .split('.')
+                    .map(|identifier| match identifier.parse::<u64>() {
+                        Ok(number) => Identifier::Numeric(number),
+                        Err(_) => Identifier::Alphanumeric(identifier.to_string()),
+                    })
+                    .collect()
+            })
+            .unwrap_or_default();
+
+        Some(PartialVersion {
+            major,
+            minor,
+            patch,
+            prerelease,
+        })
+    }
+
+    fn floor(&self) -> Version {
+        Version {
+            major: self.major.unwrap_or(0),
+            minor: self.minor.unwrap_or(0),
+            patch: self.patch.unwrap_or(0),
+            prerelease: self.prerelease.clone(),
+        }
+    }
+
+    /// The first version past everything this partial version covers, `None` for `*`.
+    fn ceiling(&self) -> Option<Version> {
+        match (self.major, self.minor, self.patch) {
+            (Some(major), None, _) => Some(Version::lowest(major + 1, 0, 0)),
+            (Some(major), Some(minor), None) => Some(Version::lowest(major, minor + 1, 0)),
+            (Some(major), Some(minor), Some(patch)) => {
+                Some(Version::lowest(major, minor, patch + 1))
+            }
+            (None, _, _) => None,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Operator {
+    Greater,
+    GreaterOrEqual,
+    Less,
+    LessOrEqual,
+    Equal,
+}
+
+#[derive(Debug, Clone)]
+struct Comparator {
+    operator: Operator,
+    version: Version,
+}
+
+impl Comparator {
+    fn new(operator: Operator, version: Version) -> Self {
+        Comparator { operator, version }
+    }
+
+    fn test(&self, version: &Version) -> bool {
+        match self.operator {
+            Operator::Greater => version > &self.version,
+            Operator::GreaterOrEqual => version >= &self.version,
+            Operator::Less => version < &self.version,
+            Operator::LessOrEqual => version <= &self.version,
+            Operator::Equal => version == &self.version,
+        }
+    }
+}
+
+/// An npm semver range: comparator sets joined by `||`, each satisfied when all of its comparators are.
+#[derive(Debug, Clone)]
+pub struct Range {
+    comparator_sets: Vec<Vec<Comparator>>,
+}
+
+impl Range {
+    pub fn parse(range: &str) -> Option<Self> {
+        let comparator_sets = range
+            .split("||")
+            .map(parse_comparator_set)
+            .collect::<Option<Vec<_>>>()?;
+
+        Some(Range { comparator_sets })
+    }
+
+    pub fn is_satisfied_by(&self, version: &Version) -> bool {
+        self.comparator_sets.iter().any(|comparators| {
+            comparators.iter().all(|comparator| comparator.test(version))
+                // Prereleases only match when a comparator opts into that exact release.
+                && (version.prerelease.is_empty()
+                    || comparators.iter().any(|comparator| {
+                        !comparator.version.prerelease.is_empty()
+                            && comparator.version.has_same_release(version)
+                    }))
+        })
+    }
+
+    /// The lowest version the range allows, mirroring `semver.minVersion`.
+    pub fn min_version(&self) -> Option<Version> {
+        for candidate in [Version::new(0, 0, 0), Version::lowest(0, 0, 0)] {
+            if self.is_satisfied_by(&candidate) {
+                return Some(candidate);
+            }
+        }
+
+        let min_version = self
+            .comparator_sets
+            .iter()
+            .filter_map(|comparators| {
+                comparators
+                    .iter()
+                    .filter_map(|comparator| match comparator.operator {
+                        Operator::Greater => {
+                            let mut version = comparator.version.clone();
+                            if version.prerelease.is_empty() {
+                                version.patch += 1;
+                            } else {
+                                version.prerelease.push(Identifier::Numeric(0));
+                            }
+                            Some(version)
+                        }
+                        Operator::GreaterOrEqual | Operator::Equal => {
+                            Some(comparator.version.clone())
+                        }
+                        Operator::Less | Operator::LessOrEqual => None,
+                    })
+                    .max()
+            })
+            .min()?;
+
+        self.is_satisfied_by(&min_version).then_some(min_version)
+    }
+
+    /// The first release past everything the range allows, `None` when it has no upper bound.
+    pub fn first_version_above(&self) -> Option<Version> {
+        self.comparator_sets
+            .iter()
+            .map(|comparators| {
+                comparators
+                    .iter()
+                    .filter_map(|comparator| {
+                        let Version {
+                            major,
+                            minor,
+                            patch,
+                            ..
+                        } = comparator.version;
+                        match comparator.operator {
+                            Operator::Less => Some(Version::new(major, minor, patch)),
+                            Operator::LessOrEqual | Operator::Equal => {
+                                Some(Version::new(major, minor, patch + 1))
+                            }
+                            Operator::Greater | Operator::GreaterOrEqual => None,
+                        }
+                    })
+                    .min()
+            })
+            .collect::<Option<Vec<_>>>()?
+            .into_iter()
+            .max()
+    }
+}
+
+fn parse_comparator_set(comparator_set: &str) -> Option<Vec<Comparator>> {
+    let comparator_set = comparator_set.trim();
+
+    if let Some((from, to)) = comparator_set.split_once(" - ") {
+        let from = PartialVersion::parse(from.trim())?;
+        let to = PartialVersion::parse(to.trim())?;
+        let mut comparators = vec![Comparator::new(Operator::GreaterOrEqual, from.floor())];
+
+        if to.patch.is_some() {
+            comparators.push(Comparator::new(Operator::LessOrEqual, to.floor()));
+        } else if let Some(ceiling) = to.ceiling() {
+            comparators.push(Comparator::new(Operator::Less, ceiling));
+        }
+
+        return Some(comparators);
+    }
+
+    let comparator_set = RE_OPERATOR_SPACE.replace_all(comparator_set, "$1");
+    let mut comparators = vec![];
+
+    for token in comparator_set.split_whitespace() {
+        comparators.extend(parse_comparator(token)?);
+    }
+
+    if comparators.is_empty() {
+        comparators.push(Comparator::new(
+            Operator::GreaterOrEqual,
+            Version::new(0, 0, 0),
+        ));
+    }
+
+    Some(comparators)
+}
+
+fn parse_comparator(token: &str) -> Option<Vec<Comparator>> {
+    let operator_length = token
+        .find(|c: char| c.is_ascii_alphanumeric() || c == '*')
+        .unwrap_or(token.len());
+    let (operator, version) = token.split_at(operator_length);
+    let partial = PartialVersion::parse(version)?;
+    let floor = partial.floor();
+    let ceiling = partial.ceiling();
+
+    let range = |ceiling: Option<Version>| {
+        let mut comparators = vec![Comparator::new(Operator::GreaterOrEqual, floor.clone())];
+        comparators.extend(ceiling.map(|ceiling| Comparator::new(Operator::Less, ceiling)));
+        comparators
+    };
+    let nothing = || vec![Comparator::new(Operator::Less, Version::lowest(0, 0, 0))];
+
+    let comparators = match operator {
+        "" | "=" => match partial.patch {
+            Some(_) => vec![Comparator::new(Operator::Equal, floor)],
+            None => range(ceiling),
+        },
+        "~" | "~>" => match (partial.major, partial.minor) {
+            (Some(major), Some(minor)) => range(Some(Version::lowest(major, minor + 1, 0))),
+            _ => range(ceiling),
+        },
+        "^" => match (partial.major, partial.minor, partial.patch) {
+            (Some(0), Some(0), Some(patch)) => range(Some(Version::lowest(0, 0, patch + 1))),
+            (Some(0), Some(minor), _) => range(Some(Version::lowest(0, minor + 1, 0))),
+            (Some(major), _, _) => range(Some(Version::lowest(major + 1, 0, 0))),
+            (None, _, _) => range(None),
+        },
+        ">" => match partial.patch {
+            Some(_) => vec![Comparator::new(Operator::Greater, floor)],
+            None => match ceiling {
+                Some(ceiling) => vec![Comparator::new(
+                    Operator::GreaterOrEqual,
+                    Version::new(ceiling.major, ceiling.minor, ceiling.patch),
+                )],
+                None => nothing(),
+            },
+        },
+        ">=" => vec![Comparator::new(Operator::GreaterOrEqual, floor)],
+        "<" => match partial.major {
+            Some(_) if partial.patch.is_some() => vec![Comparator::new(Operator::Less, floor)],
+            Some(_) => vec![Comparator::new(
+                Operator::Less,
+                Version::lowest(floor.major, floor.minor, floor.patch),
+            )],
+            None => nothing(),
+        },
+        "<=" => match partial.patch {
+            Some(_) => vec![Comparator::new(Operator::LessOrEqual, floor)],
+            None => match ceiling {
+                Some(ceiling) => vec![Comparator::new(Operator::Less, ceiling)],
+                None => range(None),
+            },
+        },
+        _ => return None,
+    };
+
+    Some(comparators)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn satisfies(range: &str, version: &str) -> bool {
+        Range::parse(range)
+            .unwrap()
+            .is_satisfied_by(&Version::parse(version).unwrap())
+    }
+
+    fn display(version: Option<Version>) -> Option<String> {
+        version.map(|version| version.to_string())
+    }
+
+    #[test]
+    fn caret_allows_changes_that_keep_the_leftmost_non_zero_part() {
+        assert!(satisfies("^1.2.3", "1.9.0"));
+        assert!(!satisfies("^1.2.3", "2.0.0"));
+        assert!(!satisfies("^1.2.3", "1.2.2"));
+        assert!(satisfies("^0.2.3", "0.2.9"));
+        assert!(!satisfies("^0.2.3", "0.3.0"));
+        assert!(satisfies("^0.0.3", "0.0.3"));
+        assert!(!satisfies("^0.0.3", "0.0.4"));
+        assert!(satisfies("^0.x", "0.9.0"));
+        assert!(!satisfies("^0.x", "1.0.0"));
+    }
+
+    #[test]
+    fn tilde_allows_patch_changes_or_minor_ones_without_a_minor() {
+        assert!(satisfies("~1.2.3", "1.2.9"));
+        assert!(!satisfies("~1.2.3", "1.3.0"));
+        assert!(satisfies("~1.2", "1.2.0"));
+        assert!(!satisfies("~1.2", "1.3.0"));
+        assert!(satisfies("~1", "1.9.9"));
+        assert!(!satisfies("~1", "2.0.0"));
+        assert!(satisfies("~> 1.2.3", "1.2.4"));
+    }
+
+    #[test]
+    fn x_ranges_allow_anything_in_the_missing_parts() {
+        assert!(satisfies("*", "3.1.4"));
+        assert!(satisfies("", "3.1.4"));
+        assert!(satisfies("1.x", "1.9.9"));
+        assert!(!satisfies("1.x", "2.0.0"));
+        assert!(satisfies("1.2.X", "1.2.7"));
+        assert!(!satisfies("1.2.*", "1.3.0"));
+        assert!(satisfies("1", "1.0.0"));
+        assert!(!satisfies(">1.x", "1.9.9"));
+        assert!(satisfies("<=1.2", "1.2.9"));
+        assert!(!satisfies("<1.2", "1.2.0"));
+    }
+
+    #[test]
+    fn hyphen_ranges_include_both_ends() {
+        assert!(satisfies("1.2.3 - 2.3.4", "1.2.3"));
+        assert!(satisfies("1.2.3 - 2.3.4", "2.3.4"));
+        assert!(!satisfies("1.2.3 - 2.3.4", "2.3.5"));
+        assert!(satisfies("1.2 - 2.3", "2.3.9"));
+        assert!(!satisfies("1.2 - 2.3", "2.4.0"));
+        assert!(!satisfies("1.2 - 2.3", "1.1.9"));
+    }
+
+    #[test]
+    fn unions_are_satisfied_by_any_of_their_sets() {
+        assert!(satisfies("^1.0.0 || ^3.0.0", "1.5.0"));
+        assert!(satisfies("^1.0.0 || ^3.0.0", "3.0.1"));
+        assert!(!satisfies("^1.0.0 || ^3.0.0", "2.0.0"));
+        assert!(satisfies(">=1.0.0 <1.5.0 || >2", "1.4.9"));
+        assert!(!satisfies(">=1.0.0 <1.5.0 || >2", "1.5.0"));
+        assert!(Range::parse("^1 || nope").is_none());
+    }
+
+    #[test]
+    fn prereleases_are_ordered_by_their_identifiers() {
+        let versions = [
+            "1.0.0-alpha",
+            "1.0.0-alpha.1",
+            "1.0.0-alpha.beta",
+            "1.0.0-beta.2",
+            "1.0.0-beta.11",
+            "1.0.0-rc.1",
+            "1.0.0",
+        ]
+        .map(|version| Version::parse(version).unwrap());
+
+        assert!(versions.windows(2).all(|pair| pair[0] < pair[1]));
+        assert_eq!(
+            Version::parse("1.0.0-rc.1+build.5").unwrap().to_string(),
+            "1.0.0-rc.1"
+        );
+    }
+
+    #[test]
+    fn prereleases_only_match_comparators_on_the_same_release() {
+        assert!(satisfies("^1.2.3-beta.2", "1.2.3-beta.4"));
+        assert!(!satisfies("^1.2.3-beta.2", "1.2.3-beta.1"));
+        assert!(!satisfies("^1.2.3-beta.2", "1.2.4-beta.1"));
+        assert!(satisfies("^1.2.3-beta.2", "1.2.4"));
+        assert!(!satisfies("^1.0.0", "1.1.0-beta"));
+        assert!(!satisfies("<2.0.0", "2.0.0-beta"));
+    }
+
+    #[test]
+    fn min_version_is_the_lowest_version_the_range_allows() {
+        let min_version = |range: &str| display(Range::parse(range).unwrap().min_version());
+
+        assert_eq!(min_version("^1.2.3"), Some("1.2.3".to_string()));
+        assert_eq!(min_version("~1.2"), Some("1.2.0".to_string()));
+        assert_eq!(min_version(">1.2.3"), Some("1.2.4".to_string()));
+        assert_eq!(min_version(">1.0.0-beta"), Some("1.0.0-beta.0".to_string()));
+        assert_eq!(min_version("*"), Some("0.0.0".to_string()));
+        assert_eq!(min_version("<1.0.0"), Some("0.0.0".to_string()));
+        assert_eq!(min_version("^2 || ^1.5"), Some("1.5.0".to_string()));
+        assert_eq!(min_version(">2 <1"), None);
+    }
+
+    #[test]
+    fn first_version_above_is_the_first_release_past_the_range() {
+        let first_version_above =
+            |range: &str| display(Range::parse(range).unwrap().first_version_above());
+
+        assert_eq!(first_version_above("^1.2.3"), Some("2.0.0".to_string()));
+        assert_eq!(first_version_above("^0.2.3"), Some("0.3.0".to_string()));
+        assert_eq!(first_version_above("~1.2 || ^3"), Some("4.0.0".to_string()));
+        assert_eq!(first_version_above("<=1.4.2"), Some("1.4.3".to_string()));
+        assert_eq!(first_version_above("1.2.3"), Some("1.2.4".to_string()));
+        assert_eq!(first_version_above(">=1"), None);
+        assert_eq!(first_version_above("^1 || >=3"), None);
+    }
+}
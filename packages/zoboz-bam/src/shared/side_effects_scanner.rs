@@ -0,0 +1,142 @@
+use lazy_static::lazy_static;
+use regex::Regex;
+
+lazy_static! {
+    static ref RE_SIDE_EFFECT_IMPORT: Regex =
+        Regex::new(r#"^(?:import\s*|require\(\s*)['"]([^'"]+)['"]\s*\)?\s*;?$"#).unwrap();
+    static ref RE_GLOBAL_ASSIGNMENT: Regex = Regex::new(
+        r"^((?:window|globalThis|global|self)(?:\.[\w$]+|\[[^\]]+\])+|[\w$]+\.prototype(?:\.[\w$]+)+)\s*=[^=]"
+    )
+    .unwrap();
+    static ref RE_CALL: Regex =
+        Regex::new(r"^(?:await\s+)?(?:new\s+)?([\w$]+(?:\.[\w$]+)*)\s*\(").unwrap();
+    // Calls through TypeScript's `(0, module_1.fn)(...)` indirection.
+    static ref RE_INDIRECT_CALL: Regex =
+        Regex::new(r"^\(\s*0\s*,\s*([\w$]+(?:\.[\w$]+)*)\s*\)\s*\(").unwrap();
+    static ref RE_IIFE: Regex = Regex::new(r"^[!;+-]?\(\s*(?:async\s+)?(?:function\b|\()").unwrap();
+    static ref RE_EXPORTS: Regex = Regex::new(r"\bexports\b").unwrap();
+}
+
+const NON_CALL_KEYWORDS: &[&str] = &[
+    "if", "for", "while", "switch", "function", "return", "catch", "with", "typeof", "void",
+    "class", "super",
+];
+
+pub enum SideEffect {
+    Import(String),
+    Call(String),
+    GlobalAssignment(String),
+}
+
+impl SideEffect {
+    pub fn describe(&self) -> String {
+        match self {
+            SideEffect::Import(specifier) => format!("a side-effect import of `{}`", specifier),
+            SideEffect::Call(callee) => format!("a top-level call to `{}`", callee),
+            SideEffect::GlobalAssignment(target) => format!("an assignment to `{}`", target),
+        }
+    }
+}
+
+/// Looks for statements that run something as soon as the module is evaluated. This is a
+/// line based heuristic: only lines starting outside of any bracket are considered top-level,
+/// and the export plumbing TypeScript and Babel emit for CommonJS is not counted.
+pub fn find_top_level_side_effects(file_content: &str) -> Vec<SideEffect> {
+    get_top_level_lines(file_content)
+        .into_iter()
+        .filter_map(|line| detect_side_effect(line.trim()))
+        .collect()
+}
+
+fn detect_side_effect(statement: &str) -> Option<SideEffect> {
+    if let Some(captures) = RE_SIDE_EFFECT_IMPORT.captures(statement) {
+        return Some(SideEffect::Import(captures[1].to_string()));
+    }
+
+    if let Some(captures) = RE_GLOBAL_ASSIGNMENT.captures(statement) {
+        return Some(SideEffect::GlobalAssignment(captures[1].to_string()));
+    }
+
+    if RE_IIFE.is_match(statement) {
+        return Some(SideEffect::Call("(function () { ... })()".to_string()));
+    }
+
+    let callee = RE_CALL
+        .captures(statement)
+        .or_else(|| RE_INDIRECT_CALL.captures(statement))
+        .map(|captures| captures[1].to_string())?;
+
+    let is_helper = callee.starts_with("__") || callee.starts_with("_interop");
+    if NON_CALL_KEYWORDS.contains(&callee.as_str()) || is_helper || RE_EXPORTS.is_match(statement) {
+        return None;
+    }
+
+    Some(SideEffect::Call(callee))
+}
+
+/// Returns the lines that start at bracket depth zero, outside of comments and strings.
+fn get_top_level_lines(file_content: &str) -> Vec<&str> {
+    enum State {
+        Code,
+        LineComment,
+        BlockComment,
+        Quoted(char),
+    }
+
+    let mut lines = vec![];
+    let mut state = State::Code;
+    let mut depth: usize = 0;
+    let mut line_start = 0;
+    let mut is_top_level_line = true;
+    let mut chars = file_content.char_indices().peekable();
+
+    while let Some((index, c)) = chars.next() {
+        if c == '\n' {
+            if is_top_level_line {
+                lines.push(&file_content[line_start..index]);
+            }
+            if let State::LineComment = state {
+                state = State::Code;
+            }
+            line_start = index + 1;
+            is_top_level_line = depth == 0 && matches!(state, State::Code);
+            continue;
+        }
+
+        match state {
+            State::Code => match c {
+                '/' if chars.peek().map(|(_, next)| *next) == Some('/') => {
+                    state = State::LineComment
+                }
+                '/' if chars.peek().map(|(_, next)| *next) == Some('*') => {
+                    chars.next();
+                    state = State::BlockComment;
+                }
+                '\'' | '"' | '`' => state = State::Quoted(c),
+                '{' | '(' | '[' => depth += 1,
+                '}' | ')' | ']' => depth = depth.saturating_sub(1),
+                _ => {}
+            },
+            State::LineComment => {}
+            State::BlockComment => {
+                if c == '*' && chars.peek().map(|(_, next)| *next) == Some('/') {
+                    chars.next();
+                    state = State::Code;
+                }
+            }
+            State::Quoted(quote) => {
+                if c == '\\' {
+                    chars.next();
+                } else if c == quote {
+                    state = State::Code;
+                }
+            }
+        }
+    }
+
+    if is_top_level_line {
+        lines.push(&file_content[line_start..]);
+    }
+
+    lines
+}
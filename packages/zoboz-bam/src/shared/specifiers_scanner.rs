@@ -0,0 +1,118 @@
+use std::collections::BTreeSet;
+
+use lazy_static::lazy_static;
+
+use super::utils;
+use super::value_objects::AbsoluteOutputDir;
+
+lazy_static! {
+    // NOTE: This regex won't work if 'require' is aliased or renamed using createRequire.
+    pub static ref RE_REQUIRE_OR_IMPORT: regex::Regex =
+        regex::Regex::new(r#"\b(require|import)(\s*\(\s*['"])(.+?)(['"]\s*\))"#).unwrap();
+    pub static ref RE_FROM: regex::Regex =
+        regex::Regex::new(r#"\b(from)(\s*['"])(.+?)(['"])"#).unwrap();
+    pub static ref RE_SIDE_EFFECT_IMPORT: regex::Regex =
+        regex::Regex::new(r#"(?m)^\s*(import)(\s*['"])(.+?)(['"])"#).unwrap();
+}
+
+// Declarations are left out on purpose, type-only imports don't need to be available at runtime.
+const RUNTIME_EXTENSIONS: &[&str] = &["js", "jsx", "mjs", "cjs"];
+
+const NODE_BUILTINS: &[&str] = &[
+    "assert",
+    "async_hooks",
+    "buffer",
+    "child_process",
+    "cluster",
+    "console",
+    "constants",
+    "crypto",
+    "dgram",
+    "diagnostics_channel",
+    "dns",
+    "domain",
+    "events",
+    "fs",
+    "http",
+    "http2",
+    "https",
+    "inspector",
+    "module",
+    "net",
+    "os",
+    "path",
+    "perf_hooks",
+    "process",
+    "punycode",
+    "querystring",
+    "readline",
+    "repl",
+    "stream",
+    "string_decoder",
+    "sys",
+    "timers",
+    "tls",
+    "trace_events",
+    "tty",
+    "url",
+    "util",
+    "v8",
+    "vm",
+    "wasi",
+    "worker_threads",
+    "zlib",
+];
+
+/// Collects every specifier passed to `require()`, `import()`, `from` or a side-effect `import`.
+pub fn collect_specifiers(file_content: &str) -> Vec<String> {
+    let mut specifiers = vec![];
+
+    for regex in [&*RE_REQUIRE_OR_IMPORT, &*RE_FROM, &*RE_SIDE_EFFECT_IMPORT] {
+        for caps in regex.captures_iter(file_content) {
+            specifiers.push(caps[3].to_string());
+        }
+    }
+
+    specifiers
+}
+
+/// Returns the package name a bare specifier points at, e.g. `@scope/pkg` for `@scope/pkg/sub`.
+/// Relative, absolute, protocol-prefixed, subpath-import and Node.js builtin specifiers yield `None`.
+pub fn get_bare_package_name(specifier: &str) -> Option<String> {
+    if specifier.is_empty()
+        || specifier.starts_with('.')
+        || specifier.starts_with('/')
+        || specifier.starts_with('#')
+        || specifier.contains(':')
+    {
+        return None;
+    }
+
+    let mut segments = specifier.split('/');
+    let first = segments.next()?;
+
+    let package_name = if first.starts_with('@') {
+        format!("{}/{}", first, segments.next()?)
+    } else {
+        first.to_string()
+    };
+
+    if NODE_BUILTINS.contains(&package_name.as_str()) {
+        return None;
+    }
+
+    Some(package_name)
+}
+
+/// Every package the runtime files of the output directories import, sorted by name.
+pub fn collect_runtime_package_names(output_dirs: &[AbsoluteOutputDir]) -> BTreeSet<String> {
+    output_dirs
+        .iter()
+        .flat_map(|output_dir| {
+            utils::list_files_recursively(output_dir.value(), RUNTIME_EXTENSIONS)
+        })
+        .filter_map(|file_path| std::fs::read_to_string(file_path).ok())
+        .flat_map(|file_content| collect_specifiers(&file_content))
+        .filter_map(|specifier| get_bare_package_name(&specifier))
+        .collect()
+}
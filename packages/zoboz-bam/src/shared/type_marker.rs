@@ -0,0 +1,45 @@
+use std::fs;
+use std::path::Path;
+
+use super::module_format::ModuleFormat;
+
+/// Writes the package.json that makes Node.js load the `.js` files of an output directory in the
+/// given format, whatever the `type` of the package itself. Same as `@zoboz/core`'s `TypeEnforcer`.
+/// A package.json that describes a package is never overwritten.
+pub fn write_type_marker(output_dir: &Path, module_format: ModuleFormat) -> std::io::Result<()> {
+    if output_dir.join("package.json").exists() && !is_type_marker(output_dir) {
+        return Err(std::io::Error::new(
+            std::io::ErrorKind::AlreadyExists,
+            "its package.json describes a package rather than marking a module format",
+        ));
+    }
+
+    fs::write(
+        output_dir.join("package.json"),
+        format!(
+            "{{\n  \"type\": \"{}\"\n}}\n",
+            module_format.get_type_field()
+        ),
+    )
+}
+
+/// The `type` of the package.json right inside the output directory, if there is one.
+pub fn read_type_marker(output_dir: &Path) -> Option<String> {
+    let marker_content = fs::read_to_string(output_dir.join("package.json")).ok()?;
+    let marker: serde_json::Value = serde_json::from_str(&marker_content).ok()?;
+
+    marker
+        .get("type")
+        .and_then(|type_field| type_field.as_str())
+        .map(|type_field| type_field.to_string())
+}
+
+/// Whether the package.json right inside the directory holds nothing but a `type`, as written by
+/// `write_type_marker`, rather than describing a package.
+pub fn is_type_marker(dir: &Path) -> bool {
+    fs::read_to_string(dir.join("package.json"))
+        .ok()
+        .and_then(|content| serde_json::from_str::<serde_json::Value>(&content).ok())
+        .and_then(|package_json| package_json.as_object().cloned())
+        .is_some_and(|package_json| package_json.keys().eq(["type"]))
+}
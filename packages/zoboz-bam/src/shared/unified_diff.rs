@@ -0,0 +1,130 @@
+const CONTEXT_LINES: usize = 3;
+
+#[derive(Clone, Copy, PartialEq, Eq)]
+enum Operation {
+    Equal,
+    Delete,
+    Insert,
+}
+
+/// Renders the changes between two texts the way `git diff` does, so the output can be applied
+/// with `git apply` or pasted into a review. Returns an empty string when the texts are equal.
+pub fn get_unified_diff(file_name: &str, old_text: &str, new_text: &str) -> String {
+    let old_lines: Vec<&str> = old_text.split_inclusive('\n').collect();
+    let new_lines: Vec<&str> = new_text.split_inclusive('\n').collect();
+    let edits = get_edits(&old_lines, &new_lines);
+
+    if edits
+        .iter()
+        .all(|(operation, _)| *operation == Operation::Equal)
+    {
+        return String::new();
+    }
+
+    let mut diff = format!("--- a/{}\n+++ b/{}\n", file_name, file_name);
+
+    for (start, end) in get_hunk_ranges(&edits) {
+        // Line numbers are counted over everything before the hunk.
+        let old_start = count_lines(&edits[..start], Operation::Insert);
+        let new_start = count_lines(&edits[..start], Operation::Delete);
+        let old_count = count_lines(&edits[start..end], Operation::Insert);
+        let new_count = count_lines(&edits[start..end], Operation::Delete);
+
+        diff.push_str(&format!(
+            "@@ -{} +{} @@\n",
+            format_range(old_start, old_count),
+            format_range(new_start, new_count)
+        ));
+
+        for (operation, line) in &edits[start..end] {
+            let prefix = match operation {
+                Operation::Equal => ' ',
+                Operation::Delete => '-',
+                Operation::Insert => '+',
+            };
+            diff.push(prefix);
+            diff.push_str(line);
+
+            if !line.ends_with('\n') {
+                diff.push_str("\n\\ No newline at end of file\n");
+            }
+        }
+    }
+
+    diff
+}
+
+/// Turns the longest common subsequence of lines into a list of kept, deleted and inserted lines.
+fn get_edits<'a>(old_lines: &[&'a str], new_lines: &[&'a str]) -> Vec<(Operation, &'a str)> {
+    let (old_count, new_count) = (old_lines.len(), new_lines.len());
+    let mut common_lengths = vec![vec![0usize; new_count + 1]; old_count + 1];
+
+    for i in (0..old_count).rev() {
+        for j in (0..new_count).rev() {
+            common_lengths[i][j] = if old_lines[i] == new_lines[j] {
+                common_lengths[i + 1][j + 1] + 1
+            } else {
+                common_lengths[i + 1][j].max(common_lengths[i][j + 1])
+            };
+        }
+    }
+
+    let mut edits = vec![];
+    let (mut i, mut j) = (0, 0);
+
+    while i < old_count || j < new_count {
+        if i < old_count && j < new_count && old_lines[i] == new_lines[j] {
+            edits.push((Operation::Equal, old_lines[i]));
+            i += 1;
+            j += 1;
+        } else if j == new_count
+            || (i < old_count && common_lengths[i + 1][j] >= common_lengths[i][j + 1])
+        {
+            edits.push((Operation::Delete, old_lines[i]));
+            i += 1;
+        } else {
+            edits.push((Operation::Insert, new_lines[j]));
+            j += 1;
+        }
+    }
+
+    edits
+}
+
+/// Groups the changed lines into hunks, merging the ones whose context would overlap.
+fn get_hunk_ranges(edits: &[(Operation, &str)]) -> Vec<(usize, usize)> {
+    let mut ranges: Vec<(usize, usize)> = vec![];
+
+    for (index, (operation, _)) in edits.iter().enumerate() {
+        if *operation == Operation::Equal {
+            continue;
+        }
+
+        let start = index.saturating_sub(CONTEXT_LINES);
+        let end = (index + 1 + CONTEXT_LINES).min(edits.len());
+
+        match ranges.last_mut() {
+            Some(last) if last.1 >= start => last.1 = end,
+            _ => ranges.push((start, end)),
+        }
+    }
+
+    ranges
+}
+
+fn count_lines(edits: &[(Operation, &str)], excluded_operation: Operation) -> usize {
+    edits
+        .iter()
+        .filter(|(operation, _)| *operation != excluded_operation)
+        .count()
+}
+
+/// `lines_before` is the number of lines preceding the hunk; an empty range points at the line
+/// before it, as in `diff -u`.
+fn format_range(lines_before: usize, count: usize) -> String {
+    match count {
+        0 => format!("{},0", lines_before),
+        1 => format!("{}", lines_before + 1),
+        _ => format!("{},{}", lines_before + 1, count),
+    }
+}
@@ -71,3 +71,71 @@ pub fn ensure_relative_prefix(specifier: PathBuf) -> String {
 
     relative_path.to_string_lossy().replace("\\", "/")
 }
+
+pub fn list_files_recursively(head_dir: &Path, allowed_extensions: &[&str]) -> Vec<PathBuf> {
+    let mut files = vec![];
+
+    if let Ok(entries) = std::fs::read_dir(head_dir) {
+        for entry in entries.flatten() {
+            let path = entry.path();
+            if path.is_dir() {
+                files.extend(list_files_recursively(&path, allowed_extensions));
+            } else if let Some(ext) = path.extension().and_then(|e| e.to_str()) {
+                if allowed_extensions.contains(&ext) {
+                    files.push(path);
+                }
+            }
+        }
+    }
+
+    files.sort();
+    files
+}
+
+/// Lists every file under `head_dir` as a `/` separated path relative to it.
+pub fn list_relative_files(head_dir: &Path, skipped_dir_names: &[&str]) -> Vec<String> {
+    let mut files = vec![];
+    collect_relative_files(head_dir, Path::new(""), skipped_dir_names, &mut files);
+
+    files.sort();
+    files
+}
+
+fn collect_relative_files(
+    head_dir: &Path,
+    relative_dir: &Path,
+    skipped_dir_names: &[&str],
+    files: &mut Vec<String>,
+) {
+    if let Ok(entries) = std::fs::read_dir(head_dir.join(relative_dir)) {
+        for entry in entries.flatten() {
+            let relative_path = relative_dir.join(entry.file_name());
+
+            if entry.path().is_dir() {
+                if !skipped_dir_names.contains(&entry.file_name().to_string_lossy().as_ref()) {
+                    collect_relative_files(head_dir, &relative_path, skipped_dir_names, files);
+                }
+            } else {
+                files.push(relative_path.to_string_lossy().replace('\\', "/"));
+            }
+        }
+    }
+}
+
+/// Lexically resolves `.` and `..` in a `/` separated relative path, `None` when it escapes its root.
+pub fn normalize_relative_path(path: &str) -> Option<String> {
+    let path = path.replace('\\', "/");
+    let mut segments: Vec<&str> = vec![];
+
+    for segment in path.split('/') {
+        match segment {
+            "" | "." => {}
+            ".." => {
+                segments.pop()?;
+            }
+            segment => segments.push(segment),
+        }
+    }
+
+    Some(segments.join("/"))
+}
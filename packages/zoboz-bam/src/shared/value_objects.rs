@@ -28,13 +28,15 @@ pub struct AbsoluteSourceDir(PathBuf);
 impl AbsoluteSourceDir {
     pub fn new(absolute_dir: &str) -> Result<Self, String> {
         let dir_path = PathBuf::from(absolute_dir);
-        if dir_path.is_absolute() {
-            Ok(Self(utils::canonical_from_buf(dir_path.to_path_buf())))
-        } else {
+        if !dir_path.is_absolute() {
             Err(format!(
                 "AbsoluteSourceDir '{}' is not an absolute path",
                 absolute_dir
             ))
+        } else if !dir_path.exists() {
+            Err(format!("AbsoluteSourceDir '{}' not found", absolute_dir))
+        } else {
+            Ok(Self(utils::canonical_from_buf(dir_path.to_path_buf())))
         }
     }
 
@@ -53,13 +55,15 @@ pub struct AbsoluteOutputDir(PathBuf);
 impl AbsoluteOutputDir {
     pub fn new(absolute_dir: &str) -> Result<Self, String> {
         let dir_path = PathBuf::from(absolute_dir);
-        if dir_path.is_absolute() {
-            Ok(Self(utils::canonical_from_buf(dir_path.to_path_buf())))
-        } else {
+        if !dir_path.is_absolute() {
             Err(format!(
                 "AbsoluteOutputDir '{}' is not an absolute path",
                 absolute_dir
             ))
+        } else if !dir_path.exists() {
+            Err(format!("AbsoluteOutputDir '{}' not found", absolute_dir))
+        } else {
+            Ok(Self(utils::canonical_from_buf(dir_path.to_path_buf())))
         }
     }
 
@@ -78,13 +82,15 @@ pub struct AbsolutePackageDir(PathBuf);
 impl AbsolutePackageDir {
     pub fn new(package_dir: &str) -> Result<Self, String> {
         let dir_path = PathBuf::from(package_dir);
-        if dir_path.is_absolute() {
-            Ok(Self(utils::canonical_from_buf(dir_path.to_path_buf())))
-        } else {
+        if !dir_path.is_absolute() {
             Err(format!(
                 "PackageDir '{}' is not an absolute path",
                 package_dir
             ))
+        } else if !dir_path.exists() {
+            Err(format!("PackageDir '{}' not found", package_dir))
+        } else {
+            Ok(Self(utils::canonical_from_buf(dir_path.to_path_buf())))
         }
     }
 
@@ -0,0 +1,120 @@
+use std::fs;
+
+use serde_json::Value;
+
+use super::glob_matcher::glob_to_regex;
+use super::package_json_reader::get_package_json;
+use super::utils;
+use super::value_objects::AbsolutePackageDir;
+
+/// Lists the packages of an npm, yarn or pnpm workspace as `/` separated paths relative to its
+/// root, sorted. `pnpm-workspace.yaml` wins over the `workspaces` field, as it does for pnpm.
+pub fn get_workspace_package_paths(
+    workspace_root: &AbsolutePackageDir,
+) -> Result<Vec<String>, String> {
+    let mut included_regexes = vec![];
+    let mut excluded_regexes = vec![];
+
+    for pattern in get_workspace_patterns(workspace_root)? {
+        let (regexes, pattern) = match pattern.strip_prefix('!') {
+            Some(pattern) => (&mut excluded_regexes, pattern),
+            None => (&mut included_regexes, pattern.as_str()),
+        };
+
+        // Patterns like `./packages/*/` name the same directories as `packages/*`.
+        if let Some(pattern) = utils::normalize_relative_path(pattern) {
+            regexes.push(glob_to_regex(&pattern));
+        }
+    }
+
+    Ok(
+        utils::list_relative_files(workspace_root.value(), &[".git", "node_modules"])
+            .iter()
+            // The root's own package.json has no directory, so it is never a package of its own.
+            .filter_map(|file| file.strip_suffix("/package.json"))
+            .filter(|dir| included_regexes.iter().any(|regex| regex.is_match(dir)))
+            .filter(|dir| !excluded_regexes.iter().any(|regex| regex.is_match(dir)))
+            .map(|dir| dir.to_string())
+            .collect(),
+    )
+}
+
+/// The closest directory, starting from the package itself, that declares workspaces.
+pub fn find_workspace_root(package_dir: &AbsolutePackageDir) -> Option<AbsolutePackageDir> {
+    package_dir
+        .value()
+        .ancestors()
+        .filter_map(|dir| AbsolutePackageDir::new(&dir.to_string_lossy()).ok())
+        .find(|dir| {
+            dir.value().join("pnpm-workspace.yaml").is_file()
+                || get_package_json(dir).workspaces.is_some()
+        })
+}
+
+fn get_workspace_patterns(workspace_root: &AbsolutePackageDir) -> Result<Vec<String>, String> {
+    if let Ok(content) = fs::read_to_string(workspace_root.value().join("pnpm-workspace.yaml")) {
+        return Ok(parse_pnpm_workspace_packages(&content));
+    }
+
+    let patterns = match get_package_json(workspace_root).workspaces {
+        Some(Value::Array(patterns)) => patterns,
+        // Yarn classic also accepts `{ "packages": [...], "nohoist": [...] }`.
+        Some(Value::Object(mut workspaces)) => match workspaces.remove("packages") {
+            Some(Value::Array(patterns)) => patterns,
+            _ => vec![],
+        },
+        _ => {
+            return Err(format!(
+                "No workspaces found in {}; list them in the `workspaces` field of package.json or in pnpm-workspace.yaml",
+                workspace_root.value().display()
+            ))
+        }
+    };
+
+    Ok(patterns
+        .iter()
+        .filter_map(|pattern| pattern.as_str().map(|pattern| pattern.to_string()))
+        .collect())
+}
+
+/// Reads the `packages` list of a `pnpm-workspace.yaml`, written as a block or a flow sequence.
+/// The rest of the file is of no interest, so this is far from a YAML parser.
+fn parse_pnpm_workspace_packages(content: &str) -> Vec<String> {
+    let mut patterns = vec![];
+    let mut is_in_packages = false;
+
+    for line in content.lines() {
+        let line = line.split(" #").next().unwrap_or_default().trim_end();
+        if line.trim().is_empty() || line.trim_start().starts_with('#') {
+            continue;
+        }
+
+        // Any other top-level key ends the list; its items may or may not be indented.
+        if !line.starts_with(char::is_whitespace) && !line.starts_with('-') {
+            is_in_packages = false;
+
+            if let Some(value) = line.strip_prefix("packages:") {
+                match value.trim().strip_prefix('[') {
+                    Some(items) => patterns.extend(
+                        items
+                            .trim_end_matches(']')
+                            .split(',')
+                            .map(unquote)
+                            .filter(|pattern| !pattern.is_empty()),
+                    ),
+                    None => is_in_packages = true,
+                }
+            }
+        } else if is_in_packages {
+            if let Some(item) = line.trim().strip_prefix('-') {
+                patterns.push(unquote(item));
+            }
+        }
+    }
+
+    patterns
+}
+
+fn unquote(value: &str) -> String {
+    value.trim().trim_matches(['\'', '"']).to_string()
+}
@@ -1,16 +1,21 @@
-pub(super) fn get_params(args: &[String]) -> Result<(String, String, String, String), String> {
-    let output_format = get_output_format(&args)?;
-
-    let absolute_package_dir = get_absolute_package_dir(&args)?;
-    let absolute_source_dir = get_absolute_source_dir(&args)?;
-    let absolute_output_dir = get_absolute_output_dir(&args)?;
+pub(super) struct Params {
+    pub output_format: String,
+    pub absolute_package_dir: String,
+    pub absolute_source_dir: String,
+    pub absolute_output_dir: String,
+    pub reporter: Option<String>,
+    pub report_file: Option<String>,
+}
 
-    Ok((
-        output_format,
-        absolute_package_dir,
-        absolute_source_dir,
-        absolute_output_dir,
-    ))
+pub(super) fn get_params(args: &[String]) -> Result<Params, String> {
+    Ok(Params {
+        output_format: get_output_format(args)?,
+        absolute_package_dir: get_absolute_package_dir(args)?,
+        absolute_source_dir: get_absolute_source_dir(args)?,
+        absolute_output_dir: get_absolute_output_dir(args)?,
+        reporter: get_reporter(args),
+        report_file: get_report_file(args),
+    })
 }
 
 fn get_output_format(args: &[String]) -> Result<String, String> {
@@ -22,9 +27,7 @@ fn get_output_format(args: &[String]) -> Result<String, String> {
 
     match output_format {
         Some(value) => Ok(value.to_string()),
-        None => Err(format!(
-            "OutputFormat not found; use --output-format <cjs|esm|dts>"
-        )),
+        None => Err("OutputFormat not found; use --output-format <cjs|esm|dts>".to_string()),
     }
 }
 
@@ -37,9 +40,9 @@ fn get_absolute_package_dir(args: &[String]) -> Result<String, String> {
 
     match absolute_package_dir {
         Some(value) => Ok(value.to_string()),
-        None => Err(format!(
-            "--absolute-package-dir not found; use --absolute-package-dir <path>"
-        )),
+        None => {
+            Err("--absolute-package-dir not found; use --absolute-package-dir <path>".to_string())
+        }
     }
 }
 
@@ -52,9 +55,9 @@ fn get_absolute_source_dir(args: &[String]) -> Result<String, String> {
 
     match absolute_source_dir {
         Some(value) => Ok(value.to_string()),
-        None => Err(format!(
-            "--absolute-source-dir not found; use --absolute-source-dir <path>"
-        )),
+        None => {
+            Err("--absolute-source-dir not found; use --absolute-source-dir <path>".to_string())
+        }
     }
 }
 
@@ -67,8 +70,22 @@ fn get_absolute_output_dir(args: &[String]) -> Result<String, String> {
 
     match absolute_output_dir {
         Some(value) => Ok(value.to_string()),
-        None => Err(format!(
-            "--absolute-output-dir not found; use --absolute-output-dir <path>"
-        )),
+        None => {
+            Err("--absolute-output-dir not found; use --absolute-output-dir <path>".to_string())
+        }
     }
 }
+
+fn get_reporter(args: &[String]) -> Option<String> {
+    args.iter()
+        .position(|arg| arg == "--reporter")
+        .and_then(|index| args.get(index + 1))
+        .map(|value| value.to_string())
+}
+
+fn get_report_file(args: &[String]) -> Option<String> {
+    args.iter()
+        .position(|arg| arg == "--report-file")
+        .and_then(|index| args.get(index + 1))
+        .map(|value| value.to_string())
+}
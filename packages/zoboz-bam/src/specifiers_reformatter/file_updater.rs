@@ -1,23 +1,18 @@
 use std::path::Path;
 
-use lazy_static::lazy_static;
+use crate::shared::reporter::Finding;
+use crate::shared::specifiers_scanner::{RE_FROM, RE_REQUIRE_OR_IMPORT};
 
 use super::specifiers_reformatter::SpecifiersReformatter;
 
-lazy_static! {
-    // NOTE: This regex won't work if 'require' is aliased or renamed using createRequire.
-    static ref RE_REQUIRE_OR_IMPORT: regex::Regex =
-        regex::Regex::new(r#"\b(require|import)(\s*\(\s*['"])(.+?)(['"]\s*\))"#).unwrap();
-    static ref RE_FROM: regex::Regex =
-        regex::Regex::new(r#"\b(from)(\s*['"])(.+?)(['"])"#).unwrap();
-}
-
 pub(super) fn update_cjs(
     specifiers_reformatter: &SpecifiersReformatter,
     file_path: &Path,
     file_content: &str,
+    findings: &mut Vec<Finding>,
 ) -> Option<String> {
-    let new_content = update_requires_and_imports(specifiers_reformatter, file_path, file_content);
+    let new_content =
+        update_requires_and_imports(specifiers_reformatter, file_path, file_content, findings);
     if new_content != file_content {
         Some(new_content.into_owned())
     } else {
@@ -29,9 +24,11 @@ pub(super) fn update_esm(
     specifiers_reformatter: &SpecifiersReformatter,
     file_path: &Path,
     file_content: &str,
+    findings: &mut Vec<Finding>,
 ) -> Option<String> {
-    let new_content = update_requires_and_imports(specifiers_reformatter, file_path, file_content);
-    let new_content = update_froms(specifiers_reformatter, file_path, &new_content);
+    let new_content =
+        update_requires_and_imports(specifiers_reformatter, file_path, file_content, findings);
+    let new_content = update_froms(specifiers_reformatter, file_path, &new_content, findings);
 
     if new_content != file_content {
         Some(new_content.into_owned())
@@ -44,9 +41,11 @@ pub(super) fn update_dts(
     specifiers_reformatter: &SpecifiersReformatter,
     file_path: &Path,
     file_content: &str,
+    findings: &mut Vec<Finding>,
 ) -> Option<String> {
-    let new_content = update_requires_and_imports(specifiers_reformatter, file_path, file_content);
-    let new_content = update_froms(specifiers_reformatter, file_path, &new_content);
+    let new_content =
+        update_requires_and_imports(specifiers_reformatter, file_path, file_content, findings);
+    let new_content = update_froms(specifiers_reformatter, file_path, &new_content, findings);
 
     if new_content != file_content {
         Some(new_content.into_owned())
@@ -59,13 +58,20 @@ fn update_requires_and_imports<'a>(
     specifiers_reformatter: &'a SpecifiersReformatter,
     file_path: &'a Path,
     file_content: &'a str,
+    findings: &mut Vec<Finding>,
 ) -> std::borrow::Cow<'a, str> {
-    let new_content = RE_REQUIRE_OR_IMPORT.replace_all(&file_content, |caps: &regex::Captures| {
+    let new_content = RE_REQUIRE_OR_IMPORT.replace_all(file_content, |caps: &regex::Captures| {
         format!(
             "{}{}{}{}",
             &caps[1],
             &caps[2],
-            specifiers_reformatter.format(&file_path, &caps[3], false),
+            format_specifier(
+                specifiers_reformatter,
+                file_path,
+                file_content,
+                caps,
+                findings
+            ),
             &caps[4]
         )
     });
@@ -77,16 +83,47 @@ fn update_froms<'a>(
     specifiers_reformatter: &'a SpecifiersReformatter,
     file_path: &'a Path,
     file_content: &'a str,
+    findings: &mut Vec<Finding>,
 ) -> std::borrow::Cow<'a, str> {
-    let new_content = RE_FROM.replace_all(&file_content, |caps: &regex::Captures| {
+    let new_content = RE_FROM.replace_all(file_content, |caps: &regex::Captures| {
         format!(
             "{}{}{}{}",
             &caps[1],
             &caps[2],
-            specifiers_reformatter.format(&file_path, &caps[3], false),
+            format_specifier(
+                specifiers_reformatter,
+                file_path,
+                file_content,
+                caps,
+                findings
+            ),
             &caps[4]
         )
     });
 
     new_content
 }
+
+/// Formats the specifier in the third capture group, noting down anything off about it.
+fn format_specifier(
+    specifiers_reformatter: &SpecifiersReformatter,
+    file_path: &Path,
+    file_content: &str,
+    caps: &regex::Captures,
+    findings: &mut Vec<Finding>,
+) -> String {
+    let specifier = &caps[3];
+    let (formatted_specifier, problem) = specifiers_reformatter.format(file_path, specifier, false);
+
+    if let Some(problem) = problem {
+        findings.push(problem.to_finding(
+            file_path,
+            file_content,
+            caps.get(3).unwrap().start(),
+            specifier,
+            &formatted_specifier,
+        ));
+    }
+
+    formatted_specifier
+}
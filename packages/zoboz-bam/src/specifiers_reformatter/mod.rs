@@ -1,9 +1,12 @@
-use cli_flags::get_params;
+use std::cell::RefCell;
+
+use cli_flags::{get_params, Params};
 use file_updater::{update_cjs, update_dts, update_esm};
 use file_walker::walk_files_recursively;
 use specifiers_reformatter::SpecifiersReformatter;
 
 use crate::shared::{
+    reporter::{Finding, Reporter},
     tsconfig_reader,
     value_objects::{self, AbsoluteOutputDir, AbsolutePackageDir, AbsoluteSourceDir, OutputFormat},
 };
@@ -12,17 +15,26 @@ mod cli_flags;
 mod file_updater;
 mod file_walker;
 mod module_resolver;
+#[allow(clippy::module_inception)]
 mod specifiers_reformatter;
 
 pub fn run_by_args(args: &[String]) -> Result<(), String> {
-    let (output_format, absolute_package_dir, absolute_source_dir, absolute_output_dir) =
-        get_params(args)?;
+    let Params {
+        output_format,
+        absolute_package_dir,
+        absolute_source_dir,
+        absolute_output_dir,
+        reporter,
+        report_file,
+    } = get_params(args)?;
 
     run_by_params(
         &output_format,
         &absolute_package_dir,
         &absolute_source_dir,
         &absolute_output_dir,
+        reporter.as_deref(),
+        report_file.as_deref(),
     )
 }
 
@@ -31,8 +43,11 @@ pub fn run_by_params(
     absolute_package_dir: &str,
     absolute_source_dir: &str,
     absolute_output_dir: &str,
+    reporter: Option<&str>,
+    report_file: Option<&str>,
 ) -> Result<(), String> {
     let output_format = OutputFormat::new(output_format)?;
+    let reporter = reporter.map(Reporter::new).transpose()?;
     let package_dir = AbsolutePackageDir::new(absolute_package_dir)?;
     let absolute_source_dir = AbsoluteSourceDir::new(absolute_source_dir)?;
     let absolute_output_dir = AbsoluteOutputDir::new(absolute_output_dir)?;
@@ -55,17 +70,27 @@ pub fn run_by_params(
     let specifiers_reformatter =
         SpecifiersReformatter::new(&package_dir, &absolute_source_dir, &absolute_output_dir);
 
+    let findings: RefCell<Vec<Finding>> = RefCell::new(vec![]);
+
     walk_files_recursively(
-        &absolute_output_dir.value(),
+        absolute_output_dir.value(),
         extensions,
-        &|file_path, file_content| match output_format.value() {
-            "esm" => update_esm(&specifiers_reformatter, file_path, file_content),
-            "cjs" => update_cjs(&specifiers_reformatter, file_path, file_content),
-            "dts" => update_dts(&specifiers_reformatter, file_path, file_content),
-            _ => panic!("Invalid format"),
+        &|file_path, file_content| {
+            let findings = &mut findings.borrow_mut();
+            match output_format.value() {
+                "esm" => update_esm(&specifiers_reformatter, file_path, file_content, findings),
+                "cjs" => update_cjs(&specifiers_reformatter, file_path, file_content, findings),
+                "dts" => update_dts(&specifiers_reformatter, file_path, file_content, findings),
+                _ => panic!("Invalid format"),
+            }
         },
     )
     .unwrap();
 
-    Ok(())
+    let mut findings = findings.into_inner();
+    findings
+        .sort_by(|a, b| (&a.file_path, a.line, a.column).cmp(&(&b.file_path, b.line, b.column)));
+
+    // Specifiers that can't be reformatted are left as they are, so they never fail the command.
+    Reporter::emit(reporter, &findings, report_file)
 }
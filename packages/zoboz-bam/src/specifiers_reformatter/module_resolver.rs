@@ -9,43 +9,45 @@ pub(super) fn create_resolver(
 ) -> oxc_resolver::Resolver {
     let aliases = get_aliases(tsconfig, src_dir, out_dir);
 
-    let mut resolve_options = oxc_resolver::ResolveOptions::default();
-    resolve_options.alias = aliases;
-    resolve_options.extensions = vec![
-        ".js".to_string(),
-        ".jsx".to_string(),
-        ".cjs".to_string(),
-        ".mjs".to_string(),
-        ".ts".to_string(),
-        ".tsx".to_string(),
-        ".json".to_string(),
-        ".ts".to_string(),
-        ".tsx".to_string(),
-        ".d.ts".to_string(),
-    ];
-    resolve_options.extension_alias = vec![
-        (
+    let resolve_options = oxc_resolver::ResolveOptions {
+        alias: aliases,
+        extensions: vec![
             ".js".to_string(),
-            vec![
-                ".js".to_string(),
-                ".ts".to_string(),
-                ".d.ts".to_string(),
-                ".tsx".to_string(),
-            ],
-        ),
-        (
             ".jsx".to_string(),
-            vec![".jsx".to_string(), ".tsx".to_string()],
-        ),
-        (
+            ".cjs".to_string(),
+            ".mjs".to_string(),
+            ".ts".to_string(),
+            ".tsx".to_string(),
+            ".json".to_string(),
             ".ts".to_string(),
-            vec![".ts".to_string(), ".tsx".to_string(), ".js".to_string()],
-        ),
-        (
             ".tsx".to_string(),
-            vec![".tsx".to_string(), ".jsx".to_string()],
-        ),
-    ];
+            ".d.ts".to_string(),
+        ],
+        extension_alias: vec![
+            (
+                ".js".to_string(),
+                vec![
+                    ".js".to_string(),
+                    ".ts".to_string(),
+                    ".d.ts".to_string(),
+                    ".tsx".to_string(),
+                ],
+            ),
+            (
+                ".jsx".to_string(),
+                vec![".jsx".to_string(), ".tsx".to_string()],
+            ),
+            (
+                ".ts".to_string(),
+                vec![".ts".to_string(), ".tsx".to_string(), ".js".to_string()],
+            ),
+            (
+                ".tsx".to_string(),
+                vec![".tsx".to_string(), ".jsx".to_string()],
+            ),
+        ],
+        ..Default::default()
+    };
 
     oxc_resolver::Resolver::new(resolve_options)
 }
@@ -1,12 +1,67 @@
 use std::path::Path;
 
 use crate::shared::{
+    reporter::{get_line_and_column, Finding, Level},
     utils,
     value_objects::{AbsoluteOutputDir, AbsolutePackageDir, AbsoluteSourceDir},
 };
 
 use super::{module_resolver::create_resolver, tsconfig_reader};
 
+// Runtime extensions a relative specifier may leave out, along with their declaration twins.
+const MODULE_SUFFIXES: &[(&str, Option<&str>)] = &[
+    (".js", Some(".d.ts")),
+    (".jsx", None),
+    (".cjs", Some(".d.cts")),
+    (".mjs", Some(".d.mts")),
+];
+
+pub(super) enum SpecifierProblem {
+    Unresolved,
+    /// Every module the specifier could point at.
+    Ambiguous(Vec<String>),
+}
+
+impl SpecifierProblem {
+    pub(super) fn to_finding(
+        &self,
+        file_path: &Path,
+        file_content: &str,
+        offset: usize,
+        specifier: &str,
+        formatted_specifier: &str,
+    ) -> Finding {
+        let (rule_id, message) = match self {
+            SpecifierProblem::Unresolved => (
+                "unresolved-specifier",
+                format!(
+                    "Specifier `{}` doesn't resolve to any file, so it was left as is.",
+                    specifier
+                ),
+            ),
+            SpecifierProblem::Ambiguous(candidates) => (
+                "ambiguous-specifier",
+                format!(
+                    "Specifier `{}` matches more than one module (`{}`); it was rewritten to `{}`.",
+                    specifier,
+                    candidates.join("`, `"),
+                    formatted_specifier
+                ),
+            ),
+        };
+        let (line, column) = get_line_and_column(file_content, offset);
+
+        Finding {
+            rule_id: rule_id.to_string(),
+            level: Level::Warning,
+            message,
+            file_path: file_path.to_path_buf(),
+            line,
+            column,
+        }
+    }
+}
+
 pub(super) struct SpecifiersReformatter {
     resolver: oxc_resolver::Resolver,
     out_dir: String,
@@ -34,11 +89,11 @@ impl SpecifiersReformatter {
         dependent_path: &Path,
         specifier: &str,
         is_trying_base_url_already: bool,
-    ) -> String {
+    ) -> (String, Option<SpecifierProblem>) {
         let dependent_dirname: Option<&str> = dependent_path.parent().and_then(|p| p.to_str());
 
         if dependent_dirname.is_none() {
-            return specifier.to_string();
+            return (specifier.to_string(), None);
         }
 
         let dependent_dirname = dependent_dirname.unwrap();
@@ -68,28 +123,74 @@ impl SpecifiersReformatter {
                 return self.format(dependent_path, specifier, true);
             }
 
-            return specifier.to_string();
+            // Bare specifiers that don't resolve are left to the consumer's node_modules.
+            let problem = is_relative(specifier).then_some(SpecifierProblem::Unresolved);
+            return (specifier.to_string(), problem);
         }
 
         let resolved = resolved.unwrap();
         let resolved_path = resolved.path().to_string_lossy();
 
         if !resolved_path.starts_with(self.out_dir.as_str()) {
-            return specifier.to_string();
+            return (specifier.to_string(), None);
         }
 
         let relative_path = utils::relative(dependent_dirname, resolved.path());
 
         let relative_path = utils::ensure_relative_prefix(relative_path);
 
-        if relative_path.ends_with(".d.ts") {
+        let relative_path = if relative_path.ends_with(".d.ts") {
             relative_path.replace(".d.ts", ".js")
         } else {
             relative_path
+        };
+
+        let candidates = if is_relative(specifier) {
+            get_candidates(Path::new(dependent_dirname), specifier)
+        } else {
+            vec![]
+        };
+
+        if candidates.len() > 1 {
+            (relative_path, Some(SpecifierProblem::Ambiguous(candidates)))
+        } else {
+            (relative_path, None)
         }
     }
 }
 
+fn is_relative(specifier: &str) -> bool {
+    specifier.starts_with("./") || specifier.starts_with("../")
+}
+
+/// The modules an extensionless relative specifier could mean; the resolver silently picks the
+/// first, so more than one of them is worth pointing out.
+fn get_candidates(dependent_dir: &Path, specifier: &str) -> Vec<String> {
+    if dependent_dir.join(specifier).is_file() {
+        return vec![];
+    }
+
+    let specifier = specifier.trim_end_matches('/');
+    let mut candidates: Vec<String> = vec![];
+
+    for base in [specifier.to_string(), format!("{}/index", specifier)] {
+        for (suffix, declaration_suffix) in MODULE_SUFFIXES {
+            let candidate = format!("{}{}", base, suffix);
+            let has_declaration = declaration_suffix.is_some_and(|declaration_suffix| {
+                dependent_dir
+                    .join(format!("{}{}", base, declaration_suffix))
+                    .is_file()
+            });
+
+            if dependent_dir.join(&candidate).is_file() || has_declaration {
+                candidates.push(candidate);
+            }
+        }
+    }
+
+    candidates
+}
+
 fn get_absolute_base_url(
     tsconfig: &tsconfig_reader::TsConfig,
     package_dir: &AbsolutePackageDir,
@@ -0,0 +1,28 @@
+pub(super) struct Params {
+    pub absolute_package_dir: String,
+    pub absolute_esm_output_dir: Option<String>,
+    pub absolute_cjs_output_dir: Option<String>,
+}
+
+pub(super) fn get_params(args: &[String]) -> Result<Params, String> {
+    let params = Params {
+        absolute_package_dir: get_required_value(args, "--absolute-package-dir")?,
+        absolute_esm_output_dir: get_value(args, "--absolute-esm-output-dir"),
+        absolute_cjs_output_dir: get_value(args, "--absolute-cjs-output-dir"),
+    };
+
+    if params.absolute_esm_output_dir.is_none() && params.absolute_cjs_output_dir.is_none() {
+        return Err("No output directory found; use --absolute-esm-output-dir <path> and/or --absolute-cjs-output-dir <path>".to_owned());
+    }
+
+    Ok(params)
+}
+
+fn get_value(args: &[String], flag: &str) -> Option<String> {
+    let index = args.iter().position(|arg| arg == flag)?;
+    args.get(index + 1).map(|value| value.to_string())
+}
+
+fn get_required_value(args: &[String], flag: &str) -> Result<String, String> {
+    get_value(args, flag).ok_or_else(|| format!("{} not found; use {} <path>", flag, flag))
+}
@@ -0,0 +1,58 @@
+mod cli_flags;
+
+use cli_flags::{get_params, Params};
+
+use crate::shared::{
+    module_format::ModuleFormat,
+    type_marker::write_type_marker,
+    value_objects::{AbsoluteOutputDir, AbsolutePackageDir},
+};
+
+pub fn run_by_args(args: &[String]) -> Result<(), String> {
+    let Params {
+        absolute_package_dir,
+        absolute_esm_output_dir,
+        absolute_cjs_output_dir,
+    } = get_params(args)?;
+
+    run_by_params(
+        &absolute_package_dir,
+        absolute_esm_output_dir.as_deref(),
+        absolute_cjs_output_dir.as_deref(),
+    )
+}
+
+pub fn run_by_params(
+    absolute_package_dir: &str,
+    absolute_esm_output_dir: Option<&str>,
+    absolute_cjs_output_dir: Option<&str>,
+) -> Result<(), String> {
+    let package_dir = AbsolutePackageDir::new(absolute_package_dir)?;
+
+    for (absolute_output_dir, module_format) in [
+        (absolute_esm_output_dir, ModuleFormat::Esm),
+        (absolute_cjs_output_dir, ModuleFormat::Cjs),
+    ] {
+        let Some(absolute_output_dir) = absolute_output_dir else {
+            continue;
+        };
+
+        let output_dir = AbsoluteOutputDir::new(absolute_output_dir)?;
+        // The package's own package.json is no marker to overwrite.
+        if !output_dir.is_package_dir_child(&package_dir)
+            || output_dir.value() == package_dir.value()
+        {
+            return Err("Output directory must be inside the package directory".to_string());
+        }
+
+        write_type_marker(output_dir.value(), module_format).map_err(|e| {
+            format!(
+                "Failed to write {}: {}",
+                output_dir.value().join("package.json").to_string_lossy(),
+                e
+            )
+        })?;
+    }
+
+    Ok(())
+}
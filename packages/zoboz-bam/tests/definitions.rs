@@ -1,7 +1,8 @@
 use cucumber::gherkin::Step;
 use cucumber::{given, then, when, World};
 use helpers::{
-    get_dir_path, get_docstring, initiate_tempdir, read_file, reformat_json, write_file, TheWorld,
+    decode_bytes, get_dir_path, get_docstring, initiate_tempdir, read_file, read_tarball_entries,
+    reformat_json, write_file, TheWorld,
 };
 use std::fs;
 use zoboz_bam::{handle_command, tokenize_input};
@@ -13,7 +14,31 @@ fn main() {
         "tests/features/specifiers_reformatter/cjs_specifiers_reformatter.feature",
         "tests/features/specifiers_reformatter/esm_specifiers_reformatter.feature",
         "tests/features/specifiers_reformatter/dts_specifiers_reformatter.feature",
+        "tests/features/specifiers_reformatter/specifier_findings.feature",
         "tests/features/package_json_verifier/type_field_remover.feature",
+        "tests/features/package_json_verifier/runtime_dependencies_availability.feature",
+        "tests/features/package_json_verifier/peer_dependencies_consistency.feature",
+        "tests/features/package_json_verifier/exports_targets_existence.feature",
+        "tests/features/package_json_verifier/exports_conditions_order.feature",
+        "tests/features/package_json_verifier/published_files_inclusion.feature",
+        "tests/features/package_json_verifier/side_effects_declaration.feature",
+        "tests/features/package_json_verifier/bin_entries_validity.feature",
+        "tests/features/package_json_verifier/package_json_formatting.feature",
+        "tests/features/package_json_verifier/package_json_diff.feature",
+        "tests/features/package_json_verifier/diagnostics_format.feature",
+        "tests/features/package_json_verifier/rules_config.feature",
+        "tests/features/package_json_verifier/reporters.feature",
+        "tests/features/package_json_verifier/workspaces.feature",
+        "tests/features/package_json_verifier/dependency_protocols_publishability.feature",
+        "tests/features/package_json_verifier/output_type_markers.feature",
+        "tests/features/exports_generator/generate_exports.feature",
+        "tests/features/resolution_verifier/verify_resolution.feature",
+        "tests/features/pack_preview/pack_preview.feature",
+        "tests/features/lockfile_verifier/verify_lockfile.feature",
+        "tests/features/type_enforcer/enforce_type.feature",
+        "tests/features/output_cleaner/clean_output.feature",
+        "tests/features/dual_package_verifier/verify_dual_package.feature",
+        "tests/features/export_names_verifier/verify_export_names.feature",
     ];
 
     for feature in features.iter() {
@@ -27,6 +52,12 @@ fn there_is_a_npm_package_with(world: &mut TheWorld, step: &Step) {
     write_file(world, "package.json", &get_docstring(step));
 }
 
+#[given(expr = "there is an npm package with the bytes:")]
+fn there_is_a_npm_package_with_the_bytes(world: &mut TheWorld, step: &Step) {
+    initiate_tempdir(world);
+    write_file(world, "package.json", &decode_bytes(&get_docstring(step)));
+}
+
 #[given(expr = "there is a file named {string} with:")]
 fn a_file_named_with(world: &mut TheWorld, step: &Step, file_name: String) {
     write_file(world, file_name.as_str(), &get_docstring(step));
@@ -56,6 +87,60 @@ fn the_json_content_for_should_be(world: &mut TheWorld, step: &Step, file_name:
     );
 }
 
+#[then(expr = "the file {string} should exist")]
+fn the_file_should_exist(world: &mut TheWorld, file_name: String) {
+    assert!(get_dir_path(world).join(file_name).exists());
+}
+
+#[then(expr = "the file {string} should not exist")]
+fn the_file_should_not_exist(world: &mut TheWorld, file_name: String) {
+    assert!(!get_dir_path(world).join(file_name).exists());
+}
+
+#[cfg(unix)]
+#[then(expr = "the file {string} should be executable")]
+fn the_file_should_be_executable(world: &mut TheWorld, file_name: String) {
+    use std::os::unix::fs::PermissionsExt;
+
+    let mode = fs::metadata(get_dir_path(world).join(file_name))
+        .unwrap()
+        .permissions()
+        .mode();
+    assert_ne!(mode & 0o111, 0);
+}
+
+#[then(expr = "the tarball {string} should contain:")]
+fn the_tarball_should_contain(world: &mut TheWorld, step: &Step, file_name: String) {
+    assert_eq!(
+        read_tarball_entries(world, &file_name).join("\n"),
+        get_docstring(step).trim()
+    );
+}
+
+#[then(expr = "the exact content for {string} should be:")]
+fn the_exact_content_for_should_be(world: &mut TheWorld, step: &Step, file_name: String) {
+    // Docstrings start with a line break, but keep the one they end with.
+    assert_eq!(
+        read_file(world, &file_name).trim_start(),
+        get_docstring(step).trim_start()
+    );
+}
+
+#[then(expr = "the bytes of {string} should be:")]
+fn the_bytes_of_should_be(world: &mut TheWorld, step: &Step, file_name: String) {
+    assert_eq!(
+        read_file(world, &file_name),
+        decode_bytes(&get_docstring(step))
+    );
+}
+
+#[then(expr = "the report {string} should be:")]
+fn the_report_should_be(world: &mut TheWorld, step: &Step, file_name: String) {
+    let expected_report =
+        get_docstring(step).replace("$scenario_dir", get_dir_path(world).to_str().unwrap());
+    assert_eq!(read_file(world, &file_name).trim(), expected_report.trim());
+}
+
 #[given(expr = "the package has a directory named {string}")]
 fn the_package_has_a_directory_named(world: &mut TheWorld, dir_path: String) {
     let path = get_dir_path(world).join(dir_path);
@@ -65,7 +150,7 @@ fn the_package_has_a_directory_named(world: &mut TheWorld, dir_path: String) {
 #[then(expr = "the result is ok")]
 fn the_result_is_ok(world: &mut TheWorld) {
     if let Some(result) = &world.command_result {
-        assert_eq!(result.is_ok(), true);
+        assert!(result.is_ok());
     } else {
         panic!("The command result is not set yet.");
     }
@@ -75,9 +160,9 @@ fn the_result_is_ok(world: &mut TheWorld) {
 fn the_result_is_error_and_equals_the_following_text(world: &mut TheWorld, step: &Step) {
     if let Some(result) = &world.command_result {
         if let Some(error) = result.clone().err() {
-            let expected_error = get_docstring(step);
+            let expected_error =
+                get_docstring(step).replace("$scenario_dir", get_dir_path(world).to_str().unwrap());
             assert_eq!(error.trim(), expected_error.trim());
-            return;
         } else {
             panic!("The command result is not error.");
         }
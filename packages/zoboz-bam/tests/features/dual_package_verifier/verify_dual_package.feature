@@ -0,0 +1,101 @@
+Feature: Warn about modules whose ESM and CJS copies can't be loaded side by side
+
+  Scenario: When the modules shipped in both builds hold no state, nothing is reported
+    Given there is an npm package with:
+      """
+      {
+        "name": "test",
+        "version": "1.0.0"
+      }
+      """
+    And there is a file named "dist/esm/index.js" with:
+      """
+      export const VERSION = '1.0.0';
+      export function add(a, b) {
+        let sum = a + b;
+        return sum;
+      }
+      """
+    And there is a file named "dist/esm/esm-only.mjs" with:
+      """
+      let counter = 0;
+      export class Counter {}
+      """
+    And there is a file named "dist/cjs/index.js" with:
+      """
+      "use strict";
+      Object.defineProperty(exports, "__esModule", { value: true });
+      exports.VERSION = '1.0.0';
+      exports.add = (a, b) => a + b;
+      """
+    When the following command is executed:
+      """
+      verify-dual-package --absolute-package-dir $scenario_dir --absolute-esm-output-dir $scenario_dir/dist/esm --absolute-cjs-output-dir $scenario_dir/dist/cjs --reporter github --report-file $scenario_dir/report.txt
+      """
+    Then the result is ok
+    And the report "report.txt" should be:
+      """
+      """
+
+  Scenario: Module-level mutable state and exported classes of modules shipped in both builds are reported
+    Given there is an npm package with:
+      """
+      {
+        "name": "test",
+        "version": "1.0.0"
+      }
+      """
+    And there is a file named "dist/esm/registry.js" with:
+      """
+      const plugins = new Map();
+      let instanceCount = 0;
+      export function register(name, plugin) {
+        instanceCount++;
+        plugins.set(name, plugin);
+      }
+      """
+    And there is a file named "dist/esm/errors.mjs" with:
+      """
+      export class ValidationError extends Error {}
+      class TimeoutError extends Error {}
+      export { TimeoutError as Timeout };
+      export default class {}
+      """
+    And there is a file named "dist/cjs/registry.js" with:
+      """
+      "use strict";
+      """
+    And there is a file named "dist/cjs/errors.cjs" with:
+      """
+      "use strict";
+      """
+    When the following command is executed:
+      """
+      verify-dual-package --absolute-package-dir $scenario_dir --absolute-esm-output-dir $scenario_dir/dist/esm --absolute-cjs-output-dir $scenario_dir/dist/cjs --reporter github --report-file $scenario_dir/report.txt
+      """
+    Then the result is ok
+    And the report "report.txt" should be:
+      """
+      ::warning file=$scenario_dir/dist/esm/errors.mjs,line=2,col=14,title=dual-package-class::`ValidationError` is an exported class, and `dist/cjs/errors.cjs` defines another one; `instanceof` fails for instances created by the other build. Make the ESM build a wrapper that re-exports the CommonJS one, or isolate the class in a CommonJS module both builds import.
+      ::warning file=$scenario_dir/dist/esm/errors.mjs,line=3,col=7,title=dual-package-class::`TimeoutError` is an exported class, and `dist/cjs/errors.cjs` defines another one; `instanceof` fails for instances created by the other build. Make the ESM build a wrapper that re-exports the CommonJS one, or isolate the class in a CommonJS module both builds import.
+      ::warning file=$scenario_dir/dist/esm/errors.mjs,line=5,col=16,title=dual-package-class::`default` is an exported class, and `dist/cjs/errors.cjs` defines another one; `instanceof` fails for instances created by the other build. Make the ESM build a wrapper that re-exports the CommonJS one, or isolate the class in a CommonJS module both builds import.
+      ::warning file=$scenario_dir/dist/esm/registry.js,line=2,col=7,title=dual-package-state::`plugins` is module-level mutable state, and `dist/cjs/registry.js` keeps its own copy of it; a consumer that loads both builds sees them drift apart. Isolate the state in a CommonJS module both builds import, or make the ESM build a wrapper that re-exports the CommonJS one.
+      ::warning file=$scenario_dir/dist/esm/registry.js,line=3,col=5,title=dual-package-state::`instanceCount` is module-level mutable state, and `dist/cjs/registry.js` keeps its own copy of it; a consumer that loads both builds sees them drift apart. Isolate the state in a CommonJS module both builds import, or make the ESM build a wrapper that re-exports the CommonJS one.
+      """
+
+  Scenario: When the CJS output directory is missing, it is an error
+    Given there is an npm package with:
+      """
+      {
+        "name": "test",
+        "version": "1.0.0"
+      }
+      """
+    When the following command is executed:
+      """
+      verify-dual-package --absolute-package-dir $scenario_dir --absolute-esm-output-dir $scenario_dir/dist/esm
+      """
+    Then the result is error and equals the following text:
+      """
+      --absolute-cjs-output-dir not found; use --absolute-cjs-output-dir <path>
+      """
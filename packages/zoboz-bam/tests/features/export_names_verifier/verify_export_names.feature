@@ -0,0 +1,137 @@
+Feature: Verify that the ESM and CJS builds of every entry point export the same names
+
+  Scenario: When Node.js detects every ESM export on the CJS build, the result is ok
+    Given there is an npm package with:
+      """
+      {
+        "name": "test",
+        "version": "1.0.0",
+        "exports": {
+          ".": {
+            "import": "./dist/esm/index.js",
+            "require": "./dist/cjs/index.js"
+          },
+          "./constants": {
+            "import": "./dist/esm/constants.js",
+            "require": "./dist/cjs/constants.js"
+          }
+        }
+      }
+      """
+    And there is a file named "dist/esm/index.js" with:
+      """
+      export * from './utils.js';
+      export { VERSION as version } from './constants.js';
+      export async function load() {}
+      export class Loader {}
+      export default load;
+      """
+    And there is a file named "dist/esm/utils.js" with:
+      """
+      export const format = (value) => `${value}`;
+      const parse = (value) => value;
+      export { parse };
+      export default format;
+      """
+    And there is a file named "dist/esm/constants.js" with:
+      """
+      export const VERSION = '1.0.0';
+      """
+    And there is a file named "dist/cjs/index.js" with:
+      """
+      "use strict";
+      Object.defineProperty(exports, "__esModule", { value: true });
+      exports.Loader = exports.version = void 0;
+      exports.load = load;
+      __exportStar(require("./utils"), exports);
+      var constants_1 = require("./constants");
+      Object.defineProperty(exports, "version", { enumerable: true, get: function () { return constants_1.VERSION; } });
+      async function load() {}
+      class Loader {}
+      exports.Loader = Loader;
+      exports.default = load;
+      """
+    And there is a file named "dist/cjs/utils.js" with:
+      """
+      const format = (value) => `${value}`;
+      const parse = (value) => value;
+      module.exports = { format, 'parse': parse };
+      """
+    And there is a file named "dist/cjs/constants.js" with:
+      """
+      exports['VERSION'] = '1.0.0';
+      """
+    When the following command is executed:
+      """
+      verify-export-names --absolute-package-dir $scenario_dir --absolute-esm-output-dir $scenario_dir/dist/esm --absolute-cjs-output-dir $scenario_dir/dist/cjs
+      """
+    Then the result is ok
+
+  Scenario: When the CJS build copies exports over at runtime, the names Node.js can't detect are errors
+    Given there is an npm package with:
+      """
+      {
+        "name": "test",
+        "version": "1.0.0",
+        "main": "./dist/cjs/index.js",
+        "module": "./dist/esm/index.mjs"
+      }
+      """
+    And there is a file named "dist/esm/index.mjs" with:
+      """
+      export * from './utils.mjs';
+      export const VERSION = '1.0.0';
+      """
+    And there is a file named "dist/esm/utils.mjs" with:
+      """
+      export function format() {}
+      export function parse() {}
+      """
+    And there is a file named "dist/cjs/index.js" with:
+      """
+      Object.assign(exports, require('./utils'));
+      exports.VERSION = '1.0.0';
+      if (exports.VERSION == null) {}
+      exports.legacy = true;
+      """
+    And there is a file named "dist/cjs/utils.js" with:
+      """
+      exports.format = function format() {};
+      exports.parse = function parse() {};
+      """
+    When the following command is executed:
+      """
+      verify-export-names --absolute-package-dir $scenario_dir --absolute-esm-output-dir $scenario_dir/dist/esm --absolute-cjs-output-dir $scenario_dir/dist/cjs
+      """
+    Then the result is error and equals the following text:
+      """
+      `format` is exported by `dist/esm/index.mjs`, but Node.js can't detect it in `dist/cjs/index.js`, so ESM code importing the CJS build can't use it by name. Assign it with `exports.format = …` instead of copying it over at runtime.
+      `parse` is exported by `dist/esm/index.mjs`, but Node.js can't detect it in `dist/cjs/index.js`, so ESM code importing the CJS build can't use it by name. Assign it with `exports.parse = …` instead of copying it over at runtime.
+      warn: `legacy` is exported by `dist/cjs/index.js`, but not by `dist/esm/index.mjs`.
+      """
+
+  Scenario: When package.json points no entry point at the ESM output, it is an error
+    Given there is an npm package with:
+      """
+      {
+        "name": "test",
+        "version": "1.0.0",
+        "main": "./dist/cjs/index.js"
+      }
+      """
+    And there is a file named "dist/esm/index.js" with:
+      """
+      export const VERSION = '1.0.0';
+      """
+    And there is a file named "dist/cjs/index.js" with:
+      """
+      exports.VERSION = '1.0.0';
+      """
+    When the following command is executed:
+      """
+      verify-export-names --absolute-package-dir $scenario_dir --absolute-esm-output-dir $scenario_dir/dist/esm --absolute-cjs-output-dir $scenario_dir/dist/cjs
+      """
+    Then the result is error and equals the following text:
+      """
+      No entry point found in dist/esm; point `exports`, `main` or `module` of package.json at it
+      """
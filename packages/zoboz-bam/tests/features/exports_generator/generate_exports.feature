@@ -0,0 +1,95 @@
+Feature: Generate "exports", "main", "module" and "types" fields
+
+  Scenario: When the fields are missing, in validate-mode, adding them is requested
+    Given there is an npm package with:
+      """
+      {
+        "name": "test",
+        "version": "1.0.0"
+      }
+      """
+    And the package has a directory named "src"
+    And the package has a directory named "dist/esm"
+    And the package has a directory named "dist/cjs"
+    And the package has a directory named "dist/dts"
+    When the following command is executed:
+      """
+      generate-exports --absolute-package-dir $scenario_dir --absolute-source-dir $scenario_dir/src --absolute-esm-output-dir $scenario_dir/dist/esm --absolute-cjs-output-dir $scenario_dir/dist/cjs --absolute-dts-output-dir $scenario_dir/dist/dts --entry-point .=./src/index.ts --entry-point ./extend=./src/extend.ts
+      """
+    Then the result is error and equals the following text:
+      """
+      Add `"main": "./dist/cjs/index.js"` to package.json
+      Add `"module": "./dist/esm/index.js"` to package.json
+      Add `"types": "./dist/dts/index.d.ts"` to package.json
+      Add `"exports": {".":{"types":"./dist/dts/index.d.ts","require":"./dist/cjs/index.js","import":"./dist/esm/index.js","default":"./dist/cjs/index.js"},"./extend":{"types":"./dist/dts/extend.d.ts","require":"./dist/cjs/extend.js","import":"./dist/esm/extend.js","default":"./dist/cjs/extend.js"}}` to package.json
+      """
+
+  Scenario: When the fields are outdated, in fix-mode, they get updated in package.json
+    Given there is an npm package with:
+      """
+      {
+        "name": "test",
+        "version": "1.0.0",
+        "main": "./lib/index.js",
+        "module": "./lib/index.mjs",
+        "exports": {
+          ".": {
+            "import": "./lib/index.mjs",
+            "require": "./lib/index.js"
+          }
+        },
+        "dependencies": {
+          "dep1": "1.0.0"
+        }
+      }
+      """
+    And the package has a directory named "src"
+    And the package has a directory named "dist/esm"
+    And the package has a directory named "dist/dts"
+    When the following command is executed:
+      """
+      generate-exports --absolute-package-dir $scenario_dir --absolute-source-dir $scenario_dir/src --absolute-esm-output-dir $scenario_dir/dist/esm --absolute-dts-output-dir $scenario_dir/dist/dts --entry-point .=./src/index.ts --can-update-package-json
+      """
+    Then the JSON content for "package.json" should be:
+      """
+      {
+        "name": "test",
+        "version": "1.0.0",
+        "module": "./dist/esm/index.js",
+        "exports": {
+          ".": {
+            "types": "./dist/dts/index.d.ts",
+            "import": "./dist/esm/index.js",
+            "default": "./dist/esm/index.js"
+          }
+        },
+        "dependencies": {
+          "dep1": "1.0.0"
+        },
+        "types": "./dist/dts/index.d.ts"
+      }
+      """
+    When the following command is executed:
+      """
+      generate-exports --absolute-package-dir $scenario_dir --absolute-source-dir $scenario_dir/src --absolute-esm-output-dir $scenario_dir/dist/esm --absolute-dts-output-dir $scenario_dir/dist/dts --entry-point .=./src/index.ts
+      """
+    Then the result is ok
+
+  Scenario: When the root entry point is missing, an error is returned
+    Given there is an npm package with:
+      """
+      {
+        "name": "test",
+        "version": "1.0.0"
+      }
+      """
+    And the package has a directory named "src"
+    And the package has a directory named "dist/esm"
+    When the following command is executed:
+      """
+      generate-exports --absolute-package-dir $scenario_dir --absolute-source-dir $scenario_dir/src --absolute-esm-output-dir $scenario_dir/dist/esm --entry-point ./extend=./src/extend.ts
+      """
+    Then the result is error and equals the following text:
+      """
+      Entry points must have a "." entry; e.g. --entry-point .=./src/index.ts
+      """
@@ -0,0 +1,212 @@
+Feature: Verify package-lock.json against package.json and the output
+
+  Scenario: When every dependency is locked within its range, the result is ok
+    Given there is an npm package with:
+      """
+      {
+        "name": "test",
+        "version": "1.0.0",
+        "dependencies": {
+          "left-pad": "^1.3.0",
+          "pad": "npm:left-pad@^1.0.0",
+          "next": "latest"
+        },
+        "peerDependencies": {
+          "react": "^18.0.0"
+        }
+      }
+      """
+    And there is a file named "package-lock.json" with:
+      """
+      {
+        "name": "test",
+        "version": "1.0.0",
+        "lockfileVersion": 3,
+        "packages": {
+          "": {
+            "name": "test",
+            "version": "1.0.0"
+          },
+          "node_modules/left-pad": {
+            "version": "1.3.0"
+          },
+          "node_modules/pad": {
+            "name": "left-pad",
+            "version": "1.1.0"
+          },
+          "node_modules/next": {
+            "version": "14.0.0"
+          }
+        }
+      }
+      """
+    When the following command is executed:
+      """
+      verify-lockfile --absolute-package-dir $scenario_dir
+      """
+    Then the result is ok
+
+  Scenario: When locked versions are off or the output imports transitive dependencies, every one of them is reported
+    Given there is an npm package with:
+      """
+      {
+        "name": "test",
+        "version": "1.0.0",
+        "dependencies": {
+          "express": "^4.18.0",
+          "left-pad": "^2.0.0"
+        },
+        "devDependencies": {
+          "typescript": "^5.0.0"
+        }
+      }
+      """
+    And there is a file named "package-lock.json" with:
+      """
+      {
+        "name": "test",
+        "version": "1.0.0",
+        "lockfileVersion": 2,
+        "packages": {
+          "": {
+            "name": "test",
+            "version": "1.0.0"
+          },
+          "node_modules/express": {
+            "version": "4.18.2",
+            "dependencies": {
+              "debug": "2.6.9",
+              "ms": "2.1.3"
+            }
+          },
+          "node_modules/express/node_modules/ms": {
+            "version": "2.1.3"
+          },
+          "node_modules/debug": {
+            "version": "2.6.9",
+            "dependencies": {
+              "ms": "2.0.0"
+            }
+          },
+          "node_modules/ms": {
+            "version": "2.0.0"
+          },
+          "node_modules/left-pad": {
+            "version": "1.3.0"
+          }
+        }
+      }
+      """
+    And there is a file named "dist/index.js" with:
+      """
+      const express = require('express');
+      const debug = require('debug');
+      const ms = require('ms');
+      const fs = require('fs');
+      const chalk = require('chalk');
+      """
+    When the following command is executed:
+      """
+      verify-lockfile --absolute-package-dir $scenario_dir --absolute-output-dir $scenario_dir/dist
+      """
+    Then the result is error and equals the following text:
+      """
+      `left-pad` is locked at `1.3.0`, which doesn't satisfy `^2.0.0` from `dependencies`; run `npm install` to update it.
+      `typescript` from `devDependencies` is missing from package-lock.json; run `npm install` to update it.
+      `debug` is imported by the output but only installed as a dependency of `express`; consumers may not get it. Declare it in `dependencies` or `peerDependencies`.
+      `ms` is imported by the output but only installed as a dependency of `debug`, `express`; consumers may not get it. Declare it in `dependencies` or `peerDependencies`.
+      """
+
+  Scenario: When the package is part of a workspace, the lockfile at the workspace root is used
+    Given there is an npm package with:
+      """
+      {
+        "name": "monorepo",
+        "private": true,
+        "workspaces": ["packages/*"]
+      }
+      """
+    And there is a file named "packages/a/package.json" with:
+      """
+      {
+        "name": "a",
+        "version": "1.0.0",
+        "dependencies": {
+          "b": "^2.0.0",
+          "left-pad": "^1.0.0"
+        }
+      }
+      """
+    And there is a file named "packages/b/package.json" with:
+      """
+      {
+        "name": "b",
+        "version": "1.0.0"
+      }
+      """
+    And there is a file named "package-lock.json" with:
+      """
+      {
+        "name": "monorepo",
+        "lockfileVersion": 3,
+        "packages": {
+          "": {
+            "name": "monorepo",
+            "workspaces": ["packages/*"]
+          },
+          "node_modules/a": {
+            "resolved": "packages/a",
+            "link": true
+          },
+          "node_modules/b": {
+            "resolved": "packages/b",
+            "link": true
+          },
+          "packages/a": {
+            "name": "a",
+            "version": "1.0.0"
+          },
+          "packages/a/node_modules/left-pad": {
+            "version": "1.3.0"
+          },
+          "packages/b": {
+            "name": "b",
+            "version": "1.0.0"
+          }
+        }
+      }
+      """
+    When the following command is executed:
+      """
+      verify-lockfile --absolute-package-dir $scenario_dir/packages/a
+      """
+    Then the result is error and equals the following text:
+      """
+      `b` is locked at `1.0.0`, which doesn't satisfy `^2.0.0` from `dependencies`; run `npm install` to update it.
+      """
+
+  Scenario: When package-lock.json is of version 1, it is an error
+    Given there is an npm package with:
+      """
+      {
+        "name": "test",
+        "version": "1.0.0"
+      }
+      """
+    And there is a file named "package-lock.json" with:
+      """
+      {
+        "name": "test",
+        "version": "1.0.0",
+        "lockfileVersion": 1,
+        "dependencies": {}
+      }
+      """
+    When the following command is executed:
+      """
+      verify-lockfile --absolute-package-dir $scenario_dir
+      """
+    Then the result is error and equals the following text:
+      """
+      lockfileVersion 1 of package-lock.json is not supported; run `npm install` with npm 7 or later to upgrade it to version 3
+      """
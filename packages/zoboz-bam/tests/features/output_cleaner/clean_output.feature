@@ -0,0 +1,206 @@
+Feature: Clean output directories with guards against deleting anything else
+
+  Scenario: Everything but the preserved files gets removed from the output directories
+    Given there is an npm package with:
+      """
+      {
+        "name": "test",
+        "version": "1.0.0"
+      }
+      """
+    And there is a file named "dist/esm/index.js" with:
+      """
+      export * from './utils/uniq.js';
+      """
+    And there is a file named "dist/esm/utils/uniq.js" with:
+      """
+      export const uniq = (items) => [...new Set(items)];
+      """
+    And there is a file named "dist/esm/package.json" with:
+      """
+      {
+        "type": "module"
+      }
+      """
+    And there is a file named "dist/esm/.gitkeep" with:
+      """
+      """
+    And there is a file named "dist/esm/assets/logo.svg" with:
+      """
+      <svg></svg>
+      """
+    And there is a file named "dist/cjs/index.js" with:
+      """
+      module.exports = {};
+      """
+    And there is a file named "dist/cjs/legacy/package.json" with:
+      """
+      {
+        "type": "commonjs"
+      }
+      """
+    When the following command is executed:
+      """
+      clean-output --absolute-package-dir $scenario_dir --absolute-output-dir $scenario_dir/dist/esm --absolute-output-dir $scenario_dir/dist/cjs --preserve .gitkeep --preserve assets
+      """
+    Then the result is ok
+    And the file "dist/esm/index.js" should not exist
+    And the file "dist/esm/utils" should not exist
+    And the file "dist/esm/package.json" should not exist
+    And the file "dist/esm/.gitkeep" should exist
+    And the file "dist/esm/assets/logo.svg" should exist
+    And the file "dist/cjs/index.js" should not exist
+    And the file "dist/cjs/legacy" should not exist
+    And the file "dist/cjs" should exist
+
+  Scenario: With --dry-run, nothing gets removed
+    Given there is an npm package with:
+      """
+      {
+        "name": "test",
+        "version": "1.0.0"
+      }
+      """
+    And there is a file named "dist/index.js" with:
+      """
+      export default 1;
+      """
+    When the following command is executed:
+      """
+      clean-output --absolute-package-dir $scenario_dir --absolute-output-dir $scenario_dir/dist --dry-run
+      """
+    Then the result is ok
+    And the file "dist/index.js" should exist
+
+  Scenario: The package directory itself is never cleaned
+    Given there is an npm package with:
+      """
+      {
+        "name": "test",
+        "version": "1.0.0"
+      }
+      """
+    When the following command is executed:
+      """
+      clean-output --absolute-package-dir $scenario_dir --absolute-output-dir $scenario_dir
+      """
+    Then the result is error and equals the following text:
+      """
+      Refusing to clean $scenario_dir: it is the package directory itself
+      """
+    And the file "package.json" should exist
+
+  Scenario: A directory outside the package is never cleaned, and neither are the other ones
+    Given there is an npm package with:
+      """
+      {
+        "name": "monorepo",
+        "private": true
+      }
+      """
+    And there is a file named "package/package.json" with:
+      """
+      {
+        "name": "test",
+        "version": "1.0.0"
+      }
+      """
+    And there is a file named "package/dist/index.js" with:
+      """
+      export default 1;
+      """
+    And there is a file named "elsewhere/important.txt" with:
+      """
+      keep me
+      """
+    When the following command is executed:
+      """
+      clean-output --absolute-package-dir $scenario_dir/package --absolute-output-dir $scenario_dir/package/dist --absolute-output-dir $scenario_dir/elsewhere
+      """
+    Then the result is error and equals the following text:
+      """
+      Refusing to clean $scenario_dir/elsewhere: it is outside the package directory
+      """
+    And the file "elsewhere/important.txt" should exist
+    And the file "package/dist/index.js" should exist
+
+  Scenario: A directory that looks like a package is never cleaned
+    Given there is an npm package with:
+      """
+      {
+        "name": "monorepo",
+        "private": true
+      }
+      """
+    And there is a file named "packages/a/package.json" with:
+      """
+      {
+        "name": "a",
+        "version": "1.0.0"
+      }
+      """
+    When the following command is executed:
+      """
+      clean-output --absolute-package-dir $scenario_dir --absolute-output-dir $scenario_dir/packages/a
+      """
+    Then the result is error and equals the following text:
+      """
+      Refusing to clean $scenario_dir/packages/a: it has a package.json, so it looks like a package rather than an output directory
+      """
+    And the file "packages/a/package.json" should exist
+
+  Scenario: A directory that holds source code is never cleaned
+    Given there is an npm package with:
+      """
+      {
+        "name": "test",
+        "version": "1.0.0"
+      }
+      """
+    And there is a file named "lib/src/index.ts" with:
+      """
+      export default 1;
+      """
+    When the following command is executed:
+      """
+      clean-output --absolute-package-dir $scenario_dir --absolute-output-dir $scenario_dir/lib
+      """
+    Then the result is error and equals the following text:
+      """
+      Refusing to clean $scenario_dir/lib: it has a `src` directory, so it looks like it holds source code
+      """
+    And the file "lib/src/index.ts" should exist
+
+  Scenario: A directory nested in an output directory is held to the same checks, unless it is preserved
+    Given there is an npm package with:
+      """
+      {
+        "name": "test",
+        "version": "1.0.0"
+      }
+      """
+    And there is a file named "dist/index.js" with:
+      """
+      export default 1;
+      """
+    And there is a file named "dist/vendor/lib/src/index.ts" with:
+      """
+      export default 1;
+      """
+    And there is a file named "dist/nested/package.json" with:
+      """
+      {
+        "name": "nested",
+        "version": "1.0.0"
+      }
+      """
+    When the following command is executed:
+      """
+      clean-output --absolute-package-dir $scenario_dir --absolute-output-dir $scenario_dir/dist --preserve vendor
+      """
+    Then the result is error and equals the following text:
+      """
+      Refusing to clean $scenario_dir/dist/nested: it has a package.json, so it looks like a package rather than an output directory
+      """
+    And the file "dist/index.js" should exist
+    And the file "dist/nested/package.json" should exist
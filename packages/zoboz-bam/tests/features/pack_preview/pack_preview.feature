@@ -0,0 +1,126 @@
+Feature: Preview and build the tarball npm would publish
+
+  Scenario: When "files" is set, only the listed files and the always published ones are packed, grouped by extension
+    Given there is an npm package with:
+      """
+      {
+        "name": "test",
+        "version": "1.0.0",
+        "bin": "./bin/cli.js",
+        "files": ["dist", "!dist/**/*.test.js"]
+      }
+      """
+    And there is a file named "README.md" with:
+      """
+      # test
+      """
+    And there is a file named "LICENSE" with:
+      """
+      MIT
+      """
+    And there is a file named "bin/cli.js" with:
+      """
+      #!/usr/bin/env node
+      """
+    And there is a file named "dist/index.js" with:
+      """
+      module.exports = {};
+      """
+    And there is a file named "dist/index.test.js" with:
+      """
+      test();
+      """
+    And there is a file named "dist/index.js.map" with:
+      """
+      {}
+      """
+    And there is a file named "src/index.ts" with:
+      """
+      export {};
+      """
+    When the following command is executed:
+      """
+      pack-preview --absolute-package-dir $scenario_dir --out $scenario_dir/test-1.0.0.tgz
+      """
+    Then the result is ok
+    And the tarball "test-1.0.0.tgz" should contain:
+      """
+      package/LICENSE 5
+      package/bin/cli.js 21
+      package/dist/index.js 22
+      package/package.json 112
+      package/dist/index.js.map 4
+      package/README.md 8
+      """
+
+  Scenario: When "files" is not set, ignore files decide, falling back to .gitignore where no .npmignore exists
+    Given there is an npm package with:
+      """
+      {
+        "name": "test",
+        "version": "1.0.0"
+      }
+      """
+    And there is a file named ".npmignore" with:
+      """
+      src
+      """
+    And there is a file named "lib/.gitignore" with:
+      """
+      *.log
+      """
+    And there is a file named "lib/index.js" with:
+      """
+      module.exports = {};
+      """
+    And there is a file named "lib/debug.log" with:
+      """
+      debug
+      """
+    And there is a file named "src/index.ts" with:
+      """
+      export {};
+      """
+    And there is a file named "node_modules/dep/index.js" with:
+      """
+      module.exports = {};
+      """
+    When the following command is executed:
+      """
+      pack-preview --absolute-package-dir $scenario_dir --out $scenario_dir/test-1.0.0.tgz
+      """
+    Then the result is ok
+    And the tarball "test-1.0.0.tgz" should contain:
+      """
+      package/lib/index.js 22
+      package/package.json 44
+      """
+
+  Scenario: When there is no package.json, the result is error
+    Given there is an npm package with:
+      """
+      {}
+      """
+    And the package has a directory named "nested"
+    When the following command is executed:
+      """
+      pack-preview --absolute-package-dir $scenario_dir/nested
+      """
+    Then the result is error and equals the following text:
+      """
+      No package.json found in '$scenario_dir/nested'
+      """
+
+  Scenario: When the package directory doesn't exist, the result is error
+    Given there is an npm package with:
+      """
+      {}
+      """
+    When the following command is executed:
+      """
+      pack-preview --absolute-package-dir $scenario_dir/missing
+      """
+    Then the result is error and equals the following text:
+      """
+      PackageDir '$scenario_dir/missing' not found
+      """
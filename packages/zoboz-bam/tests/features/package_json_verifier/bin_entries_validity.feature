@@ -0,0 +1,107 @@
+Feature: Ensure every "bin" entry can be run by Node.js
+
+  Scenario: When a bin file has no shebang and is not executable, in validate-mode, both are reported
+    Given there is an npm package with:
+      """
+      {
+        "name": "@scope/test",
+        "version": "1.0.0",
+        "bin": "./bin/cli.js"
+      }
+      """
+    And there is a file named "bin/cli.js" with:
+      """
+      console.log('hello');
+      """
+    When the following command is executed:
+      """
+      verify-package-json --absolute-package-dir $scenario_dir
+      """
+    Then the result is error and equals the following text:
+      """
+      Bin `test` file `./bin/cli.js` does not start with `#!/usr/bin/env node`.
+      Bin `test` file `./bin/cli.js` is not executable.
+      """
+
+  Scenario: When a bin file has no shebang and is not executable, in fix-mode, both get fixed
+    Given there is an npm package with:
+      """
+      {
+        "name": "test",
+        "version": "1.0.0",
+        "bin": {
+          "test": "./bin/cli.js"
+        }
+      }
+      """
+    And there is a file named "bin/cli.js" with:
+      """
+      console.log('hello');
+      """
+    When the following command is executed:
+      """
+      verify-package-json --absolute-package-dir $scenario_dir --can-update-package-json
+      """
+    Then the result is ok
+    And the JS content for "bin/cli.js" should be:
+      """
+      #!/usr/bin/env node
+
+      console.log('hello');
+      """
+    And the file "bin/cli.js" should be executable
+
+  Scenario: When a bin file is written as ESM but would run as CJS, even in fix-mode, it is reported
+    Given there is an npm package with:
+      """
+      {
+        "name": "test",
+        "version": "1.0.0",
+        "bin": {
+          "test": "./bin/cli.js",
+          "other": "./bin/other.js"
+        }
+      }
+      """
+    And there is a file named "bin/cli.js" with:
+      """
+      #!/usr/bin/env node
+      import { run } from '../dist/index.mjs';
+      run();
+      """
+    When the following command is executed:
+      """
+      verify-package-json --absolute-package-dir $scenario_dir --can-update-package-json
+      """
+    Then the result is error and equals the following text:
+      """
+      Bin `test` file `./bin/cli.js` is written as ESM but Node.js will run it as CJS; give it the `.mjs` extension.
+      Bin `other` points to `./bin/other.js`, which does not exist.
+      """
+
+  Scenario: When a bin file is written as CJS but would run as ESM, even in fix-mode, it is reported
+    Given there is an npm package with:
+      """
+      {
+        "name": "test",
+        "version": "1.0.0",
+        "type": "module",
+        "bin": {
+          "test": "./bin/cli.js"
+        }
+      }
+      """
+    And there is a file named "bin/cli.js" with:
+      """
+      #!/usr/bin/env node
+      const { run } = require('../dist/index.cjs');
+      run();
+      """
+    When the following command is executed:
+      """
+      verify-package-json --absolute-package-dir $scenario_dir --can-update-package-json
+      """
+    Then the result is error and equals the following text:
+      """
+      Bin `test` file `./bin/cli.js` is written as CJS but Node.js will run it as ESM; give it the `.cjs` extension.
+      """
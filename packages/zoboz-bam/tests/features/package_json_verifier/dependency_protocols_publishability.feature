@@ -0,0 +1,112 @@
+Feature: Ensure dependencies don't use protocols that only resolve inside the repository
+
+  Scenario: When dependencies use workspace, file or link protocols, in validate-mode, every one of them is reported
+    Given there is an npm package with:
+      """
+      {
+        "name": "monorepo",
+        "private": true,
+        "workspaces": ["packages/*"]
+      }
+      """
+    And there is a file named "packages/a/package.json" with:
+      """
+      {
+        "name": "a",
+        "version": "1.0.0",
+        "dependencies": {
+          "b": "workspace:^",
+          "c": "file:../c",
+          "left-pad": "^1.3.0",
+          "missing": "link:../missing"
+        },
+        "peerDependencies": {
+          "b": "workspace:*"
+        },
+        "devDependencies": {
+          "b": "workspace:*"
+        }
+      }
+      """
+    And there is a file named "packages/b/package.json" with:
+      """
+      {
+        "name": "b",
+        "version": "2.1.0"
+      }
+      """
+    And there is a file named "packages/c/package.json" with:
+      """
+      {
+        "name": "c",
+        "version": "0.3.1"
+      }
+      """
+    When the following command is executed:
+      """
+      verify-package-json --absolute-package-dir $scenario_dir/packages/a
+      """
+    Then the result is error and equals the following text:
+      """
+      `b` in `dependencies` is `workspace:^`, which only resolves inside this repository, so consumers can't install it. Use `^2.1.0` instead.
+      `c` in `dependencies` is `file:../c`, which only resolves inside this repository, so consumers can't install it. Use `^0.3.1` instead.
+      `missing` in `dependencies` is `link:../missing`, which only resolves inside this repository, so consumers can't install it. No sibling package provides its version, so it has to be replaced by hand.
+      `b` in `peerDependencies` is `workspace:*`, which only resolves inside this repository, so consumers can't install it. Use `2.1.0` instead.
+      """
+
+  Scenario: When dependencies use workspace protocols, in fix-mode, they get the version range of their sibling
+    Given there is an npm package with:
+      """
+      {
+        "name": "monorepo",
+        "private": true
+      }
+      """
+    And there is a file named "pnpm-workspace.yaml" with:
+      """
+      packages:
+        - "packages/*"
+      """
+    And there is a file named "packages/a/package.json" with:
+      """
+      {
+        "name": "a",
+        "version": "1.0.0",
+        "dependencies": {
+          "b": "workspace:~",
+          "c": "workspace:^0.3.0",
+          "d": "workspace:c@*"
+        }
+      }
+      """
+    And there is a file named "packages/b/package.json" with:
+      """
+      {
+        "name": "b",
+        "version": "2.1.0"
+      }
+      """
+    And there is a file named "packages/c/package.json" with:
+      """
+      {
+        "name": "c",
+        "version": "0.3.1"
+      }
+      """
+    When the following command is executed:
+      """
+      verify-package-json --absolute-package-dir $scenario_dir/packages/a --can-update-package-json
+      """
+    Then the result is ok
+    And the JSON content for "packages/a/package.json" should be:
+      """
+      {
+        "name": "a",
+        "version": "1.0.0",
+        "dependencies": {
+          "b": "~2.1.0",
+          "c": "^0.3.0",
+          "d": "npm:c@0.3.1"
+        }
+      }
+      """
@@ -0,0 +1,93 @@
+Feature: Report verifier diagnostics with rule IDs, severities and JSON paths
+
+  Scenario: When --format json is given, in validate-mode, every diagnostic is reported as JSON
+    Given there is an npm package with:
+      """
+      {
+        "name": "test",
+        "version": "1.0.0",
+        "type": "module",
+        "exports": {
+          ".": {
+            "import": "./dist/index.js"
+          }
+        },
+        "peerDependenciesMeta": {
+          "react": {
+            "optional": true
+          }
+        }
+      }
+      """
+    When the following command is executed:
+      """
+      verify-package-json --absolute-package-dir $scenario_dir --format json
+      """
+    Then the result is error and equals the following text:
+      """
+      [
+        {
+          "ruleId": "type-field-remover",
+          "severity": "error",
+          "jsonPath": "/type",
+          "message": "Field `type` in package.json should not exist. https://github.com/dariushalipour/zoboz/blob/main/packages/zoboz-bam/src/package_json_verifier/README.md",
+          "fixable": true
+        },
+        {
+          "ruleId": "peer-dependencies-meta-consistency",
+          "severity": "warn",
+          "jsonPath": "/peerDependenciesMeta/react",
+          "message": "`peerDependenciesMeta` describes `react`, which is not listed in `peerDependencies`.",
+          "fixable": false
+        },
+        {
+          "ruleId": "exports-targets-existence",
+          "severity": "error",
+          "jsonPath": "/exports/./import",
+          "message": "Target `./dist/index.js` of `exports[\".\"][\"import\"]` matches no file.",
+          "fixable": false
+        }
+      ]
+      """
+
+  Scenario: When only diagnostics below error are found, in validate-mode, the result is ok
+    Given there is an npm package with:
+      """
+      {
+        "name": "test",
+        "version": "1.0.0",
+        "sideEffects": true
+      }
+      """
+    And there is a file named "dist/index.js" with:
+      """
+      export const value = 1;
+      """
+    And there is a file named "dist/package.json" with:
+      """
+      {
+        "type": "module"
+      }
+      """
+    When the following command is executed:
+      """
+      verify-package-json --absolute-package-dir $scenario_dir --absolute-output-dir $scenario_dir/dist
+      """
+    Then the result is ok
+
+  Scenario: When an unknown format is given, it is an error
+    Given there is an npm package with:
+      """
+      {
+        "name": "test",
+        "version": "1.0.0"
+      }
+      """
+    When the following command is executed:
+      """
+      verify-package-json --absolute-package-dir $scenario_dir --format xml
+      """
+    Then the result is error and equals the following text:
+      """
+      Invalid format 'xml'; use `text` or `json`
+      """
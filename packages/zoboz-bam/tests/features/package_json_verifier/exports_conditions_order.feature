@@ -0,0 +1,314 @@
+Feature: Ensure conditions inside "exports" are ordered the way resolvers read them
+
+  Scenario: When conditions are ordered correctly, in validate-mode, no change is requested
+    Given there is an npm package with:
+      """
+      {
+        "name": "test",
+        "version": "1.0.0",
+        "exports": {
+          ".": {
+            "types": "./index.d.ts",
+            "browser": "./index.browser.js",
+            "import": "./index.mjs",
+            "require": "./index.js",
+            "default": "./index.js"
+          }
+        }
+      }
+      """
+    And there is a file named "index.d.ts" with:
+      """
+      export {};
+      """
+    And there is a file named "index.browser.js" with:
+      """
+      export {};
+      """
+    And there is a file named "index.mjs" with:
+      """
+      export {};
+      """
+    And there is a file named "index.js" with:
+      """
+      module.exports = {};
+      """
+    When the following command is executed:
+      """
+      verify-package-json --absolute-package-dir $scenario_dir
+      """
+    Then the result is ok
+
+  Scenario: When conditions are misordered, in validate-mode, every misordered object is reported
+    Given there is an npm package with:
+      """
+      {
+        "name": "test",
+        "version": "1.0.0",
+        "exports": {
+          ".": {
+            "import": {
+              "default": "./index.mjs",
+              "types": "./index.d.mts"
+            },
+            "types": "./index.d.ts",
+            "require": "./index.js"
+          },
+          "./feature.js": {
+            "default": "./feature.js",
+            "node": "./feature.node.js",
+            "worker": "./feature.worker.js"
+          }
+        }
+      }
+      """
+    And there is a file named "index.d.ts" with:
+      """
+      export {};
+      """
+    And there is a file named "index.d.mts" with:
+      """
+      export {};
+      """
+    And there is a file named "index.mjs" with:
+      """
+      export {};
+      """
+    And there is a file named "index.js" with:
+      """
+      module.exports = {};
+      """
+    And there is a file named "feature.js" with:
+      """
+      module.exports = {};
+      """
+    And there is a file named "feature.node.js" with:
+      """
+      module.exports = {};
+      """
+    And there is a file named "feature.worker.js" with:
+      """
+      module.exports = {};
+      """
+    When the following command is executed:
+      """
+      verify-package-json --absolute-package-dir $scenario_dir
+      """
+    Then the result is error and equals the following text:
+      """
+      Conditions of `exports["."]` should be ordered as `types`, `import`, `require` instead of `import`, `types`, `require`; `types` must come first, custom conditions before the generic ones, and `default` last.
+      Conditions of `exports["."]["import"]` should be ordered as `types`, `default` instead of `default`, `types`; `types` must come first, custom conditions before the generic ones, and `default` last.
+      Conditions of `exports["./feature.js"]` should be ordered as `worker`, `node`, `default` instead of `default`, `node`, `worker`; `types` must come first, custom conditions before the generic ones, and `default` last.
+      """
+
+  Scenario: When conditions are misordered, in fix-mode, they get reordered in package.json
+    Given there is an npm package with:
+      """
+      {
+        "name": "test",
+        "version": "1.0.0",
+        "exports": {
+          ".": {
+            "import": {
+              "default": "./index.mjs",
+              "types": "./index.d.mts"
+            },
+            "types": "./index.d.ts",
+            "require": "./index.js"
+          },
+          "./package.json": "./package.json"
+        },
+        "dependencies": {
+          "dep1": "1.0.0"
+        }
+      }
+      """
+    And there is a file named "index.d.ts" with:
+      """
+      export {};
+      """
+    And there is a file named "index.d.mts" with:
+      """
+      export {};
+      """
+    And there is a file named "index.mjs" with:
+      """
+      export {};
+      """
+    And there is a file named "index.js" with:
+      """
+      module.exports = {};
+      """
+    When the following command is executed:
+      """
+      verify-package-json --absolute-package-dir $scenario_dir --can-update-package-json
+      """
+    Then the JSON content for "package.json" should be:
+      """
+      {
+        "name": "test",
+        "version": "1.0.0",
+        "exports": {
+          ".": {
+            "types": "./index.d.ts",
+            "import": {
+              "types": "./index.d.mts",
+              "default": "./index.mjs"
+            },
+            "require": "./index.js"
+          },
+          "./package.json": "./package.json"
+        },
+        "dependencies": {
+          "dep1": "1.0.0"
+        }
+      }
+      """
+
+  Scenario: When conditions nested under a dotted subpath are misordered, in fix-mode, only that object gets reordered
+    Given there is an npm package with:
+      """
+      {
+        "name": "test",
+        "version": "1.0.0",
+        "exports": {
+          "./feature.js": {
+            "types": "./feature.d.ts",
+            "import": {
+              "default": "./feature.mjs",
+              "types": "./feature.d.mts"
+            }
+          },
+          "./package.json": "./package.json"
+        }
+      }
+      """
+    And there is a file named "feature.d.ts" with:
+      """
+      export {};
+      """
+    And there is a file named "feature.d.mts" with:
+      """
+      export {};
+      """
+    And there is a file named "feature.mjs" with:
+      """
+      export {};
+      """
+    When the following command is executed:
+      """
+      verify-package-json --absolute-package-dir $scenario_dir --can-update-package-json
+      """
+    Then the result is ok
+    And the JSON content for "package.json" should be:
+      """
+      {
+        "name": "test",
+        "version": "1.0.0",
+        "exports": {
+          "./feature.js": {
+            "types": "./feature.d.ts",
+            "import": {
+              "types": "./feature.d.mts",
+              "default": "./feature.mjs"
+            }
+          },
+          "./package.json": "./package.json"
+        }
+      }
+      """
+
+  Scenario: When conditions inside a fallback array are misordered, in fix-mode, the object in the array gets reordered
+    Given there is an npm package with:
+      """
+      {
+        "name": "test",
+        "version": "1.0.0",
+        "exports": {
+          ".": [
+            {
+              "import": "./dist/a.js",
+              "types": "./dist/a.d.ts"
+            },
+            "./dist/a.js"
+          ]
+        }
+      }
+      """
+    And there is a file named "dist/a.js" with:
+      """
+      export {};
+      """
+    And there is a file named "dist/a.d.ts" with:
+      """
+      export {};
+      """
+    When the following command is executed:
+      """
+      verify-package-json --absolute-package-dir $scenario_dir --can-update-package-json
+      """
+    Then the result is ok
+    And the JSON content for "package.json" should be:
+      """
+      {
+        "name": "test",
+        "version": "1.0.0",
+        "exports": {
+          ".": [
+            {
+              "types": "./dist/a.d.ts",
+              "import": "./dist/a.js"
+            },
+            "./dist/a.js"
+          ]
+        }
+      }
+      """
+
+  Scenario: When conditions inside a fallback array are misordered, with --diff, the reordering is shown
+    Given there is an npm package with:
+      """
+      {
+        "name": "test",
+        "version": "1.0.0",
+        "exports": {
+          ".": [
+            {
+              "import": "./dist/a.js",
+              "types": "./dist/a.d.ts"
+            },
+            "./dist/a.js"
+          ]
+        }
+      }
+      """
+    And there is a file named "dist/a.js" with:
+      """
+      export {};
+      """
+    And there is a file named "dist/a.d.ts" with:
+      """
+      export {};
+      """
+    When the following command is executed:
+      """
+      verify-package-json --absolute-package-dir $scenario_dir --diff
+      """
+    Then the result is error and equals the following text:
+      """
+      Conditions of `exports["."][0]` should be ordered as `types`, `import` instead of `import`, `types`; `types` must come first, custom conditions before the generic ones, and `default` last.
+
+      --- a/package.json
+      +++ b/package.json
+      @@ -5,8 +5,8 @@
+         "exports": {
+           ".": [
+             {
+      -        "import": "./dist/a.js",
+      -        "types": "./dist/a.d.ts"
+      +        "types": "./dist/a.d.ts",
+      +        "import": "./dist/a.js"
+             },
+             "./dist/a.js"
+           ]
+      """
@@ -0,0 +1,118 @@
+Feature: Ensure every "exports" target exists on disk
+
+  Scenario: When every exports target matches a file, in validate-mode, no change is requested
+    Given there is an npm package with:
+      """
+      {
+        "name": "test",
+        "version": "1.0.0",
+        "exports": {
+          ".": {
+            "types": "./dist/dts/index.d.ts",
+            "require": "./dist/cjs/index.js",
+            "import": "./dist/esm/index.js"
+          },
+          "./utils/*": {
+            "import": "./dist/esm/utils/*.js"
+          },
+          "./internal/*": null,
+          "./fallback": ["./dist/esm/index.js", "./dist/cjs/index.js"],
+          "./package.json": "./package.json"
+        }
+      }
+      """
+    And there is a file named "dist/dts/index.d.ts" with:
+      """
+      export declare const foo: string;
+      """
+    And there is a file named "dist/cjs/index.js" with:
+      """
+      module.exports.foo = 'foo';
+      """
+    And there is a file named "dist/esm/index.js" with:
+      """
+      export const foo = 'foo';
+      """
+    And there is a file named "dist/esm/utils/deep/uniq.js" with:
+      """
+      export const uniq = 'uniq';
+      """
+    When the following command is executed:
+      """
+      verify-package-json --absolute-package-dir $scenario_dir
+      """
+    Then the result is ok
+
+  Scenario: When exports targets are broken, in validate-mode, every broken target is reported
+    Given there is an npm package with:
+      """
+      {
+        "name": "test",
+        "version": "1.0.0",
+        "exports": {
+          ".": {
+            "types": "./dist/dts/index.d.ts",
+            "require": "./dist/cjs/index.js",
+            "import": "dist/esm/index.js"
+          },
+          "./utils/*": {
+            "node": {
+              "import": "./dist/esm/utils/*.mjs"
+            }
+          },
+          "./escape": "./dist/../../sibling/index.js",
+          "./fallback": ["./dist/esm/index.js", "./dist/esm/missing.js"]
+        }
+      }
+      """
+    And there is a file named "dist/cjs/index.js" with:
+      """
+      module.exports.foo = 'foo';
+      """
+    And there is a file named "dist/esm/index.js" with:
+      """
+      export const foo = 'foo';
+      """
+    And there is a file named "dist/esm/utils/uniq.js" with:
+      """
+      export const uniq = 'uniq';
+      """
+    When the following command is executed:
+      """
+      verify-package-json --absolute-package-dir $scenario_dir
+      """
+    Then the result is error and equals the following text:
+      """
+      Target `./dist/dts/index.d.ts` of `exports["."]["types"]` matches no file.
+      Target `dist/esm/index.js` of `exports["."]["import"]` is missing its leading `./`.
+      Target `./dist/esm/utils/*.mjs` of `exports["./utils/*"]["node"]["import"]` matches no file.
+      Target `./dist/../../sibling/index.js` of `exports["./escape"]` points outside the package.
+      Target `./dist/esm/missing.js` of `exports["./fallback"][1]` matches no file.
+      """
+
+  Scenario: When exports targets are broken, in fix-mode, they are still reported since they can't be fixed automatically
+    Given there is an npm package with:
+      """
+      {
+        "name": "test",
+        "version": "1.0.0",
+        "type": "module",
+        "exports": "./dist/index.js"
+      }
+      """
+    When the following command is executed:
+      """
+      verify-package-json --absolute-package-dir $scenario_dir --can-update-package-json
+      """
+    Then the result is error and equals the following text:
+      """
+      Target `./dist/index.js` of `exports` matches no file.
+      """
+    And the JSON content for "package.json" should be:
+      """
+      {
+        "name": "test",
+        "version": "1.0.0",
+        "exports": "./dist/index.js"
+      }
+      """
@@ -0,0 +1,170 @@
+Feature: Ensure every output directory has a type marker matching its files
+
+  Scenario: When markers are missing or don't match, in validate-mode, every output directory is reported
+    Given there is an npm package with:
+      """
+      {
+        "name": "test",
+        "version": "1.0.0"
+      }
+      """
+    And there is a file named "dist/esm/index.js" with:
+      """
+      export * from './utils.js';
+      """
+    And there is a file named "dist/esm/utils.js" with:
+      """
+      globalThis.ready = true;
+      """
+    And there is a file named "dist/cjs/index.js" with:
+      """
+      exports.value = 1;
+      """
+    And there is a file named "dist/cjs/package.json" with:
+      """
+      {
+        "type": "module"
+      }
+      """
+    And there is a file named "dist/mixed/index.js" with:
+      """
+      import value from './value.js';
+      """
+    And there is a file named "dist/mixed/value.js" with:
+      """
+      module.exports = 1;
+      """
+    And there is a file named "dist/explicit/index.mjs" with:
+      """
+      export default 1;
+      """
+    When the following command is executed:
+      """
+      verify-package-json --absolute-package-dir $scenario_dir --absolute-output-dir $scenario_dir/dist/esm --absolute-output-dir $scenario_dir/dist/cjs --absolute-output-dir $scenario_dir/dist/mixed --absolute-output-dir $scenario_dir/dist/explicit
+      """
+    Then the result is error and equals the following text:
+      """
+      Output directory `dist/esm` has ESM `.js` files, but no package.json marker with `"type": "module"`; Node.js would load them as the package's `type` says.
+      Output directory `dist/cjs` has CJS `.js` files, but its package.json marker says `"type": "module"`.
+      Output directory `dist/mixed` mixes ESM and CJS `.js` files, so no `type` marker fits all of them; give one of them `.mjs` or `.cjs` extensions.
+      """
+
+  Scenario: When markers are missing or don't match, in fix-mode, they get written
+    Given there is an npm package with:
+      """
+      {
+        "name": "test",
+        "version": "1.0.0"
+      }
+      """
+    And there is a file named "dist/esm/index.js" with:
+      """
+      export default 1;
+      """
+    And there is a file named "dist/cjs/index.js" with:
+      """
+      module.exports = 1;
+      """
+    And there is a file named "dist/cjs/package.json" with:
+      """
+      {
+        "type": "module"
+      }
+      """
+    When the following command is executed:
+      """
+      verify-package-json --absolute-package-dir $scenario_dir --absolute-output-dir $scenario_dir/dist/esm --absolute-output-dir $scenario_dir/dist/cjs --can-update-package-json
+      """
+    Then the result is ok
+    And the exact content for "dist/esm/package.json" should be:
+      """
+      {
+        "type": "module"
+      }
+      """
+    And the exact content for "dist/cjs/package.json" should be:
+      """
+      {
+        "type": "commonjs"
+      }
+      """
+
+  Scenario: When the output directory is the package directory, in fix-mode, its package.json is left untouched
+    Given there is an npm package with:
+      """
+      {
+        "name": "test",
+        "version": "1.0.0"
+      }
+      """
+    And there is a file named "index.js" with:
+      """
+      export default 1;
+      """
+    When the following command is executed:
+      """
+      verify-package-json --absolute-package-dir $scenario_dir --absolute-output-dir $scenario_dir --can-update-package-json
+      """
+    Then the result is error and equals the following text:
+      """
+      Output directory `.` needs `"type": "module"`, but its package.json describes a package rather than marking a module format, so it isn't overwritten; set `type` in it by hand.
+      """
+    And the JSON content for "package.json" should be:
+      """
+      {
+        "name": "test",
+        "version": "1.0.0"
+      }
+      """
+
+  Scenario: When an output directory has a package.json that isn't a marker, in fix-mode, it is left untouched
+    Given there is an npm package with:
+      """
+      {
+        "name": "test",
+        "version": "1.0.0"
+      }
+      """
+    And there is a file named "dist/esm/index.js" with:
+      """
+      export default 1;
+      """
+    And there is a file named "dist/esm/package.json" with:
+      """
+      {
+        "name": "test-esm",
+        "private": true
+      }
+      """
+    When the following command is executed:
+      """
+      verify-package-json --absolute-package-dir $scenario_dir --absolute-output-dir $scenario_dir/dist/esm --can-update-package-json
+      """
+    Then the result is error and equals the following text:
+      """
+      Output directory `dist/esm` needs `"type": "module"`, but its package.json describes a package rather than marking a module format, so it isn't overwritten; set `type` in it by hand.
+      """
+    And the JSON content for "dist/esm/package.json" should be:
+      """
+      {
+        "name": "test-esm",
+        "private": true
+      }
+      """
+
+  Scenario: When an output directory doesn't exist, it is an error
+    Given there is an npm package with:
+      """
+      {
+        "name": "test",
+        "version": "1.0.0"
+      }
+      """
+    When the following command is executed:
+      """
+      verify-package-json --absolute-package-dir $scenario_dir --absolute-output-dir $scenario_dir/dist/esm
+      """
+    Then the result is error and equals the following text:
+      """
+      AbsoluteOutputDir '$scenario_dir/dist/esm' not found
+      """
@@ -0,0 +1,86 @@
+Feature: Show the proposed package.json changes as a unified diff
+
+  Scenario: When --diff is given, in validate-mode, the fixes are shown as a diff without touching package.json
+    Given there is an npm package with:
+      """
+      {
+        "name": "test",
+        "version": "1.0.0",
+        "type": "module",
+        "main": "dist/index.js",
+        "files": ["dist"],
+        "license": "MIT",
+        "author": "someone",
+        "keywords": ["a"],
+        "peerDependencies": {
+          "react": "^18.0.0"
+        }
+      }
+      """
+    And there is a file named "dist/index.js" with:
+      """
+      module.exports = 1;
+      """
+    When the following command is executed:
+      """
+      verify-package-json --absolute-package-dir $scenario_dir --diff
+      """
+    Then the result is error and equals the following text:
+      """
+      Field `type` in package.json should not exist. https://github.com/dariushalipour/zoboz/blob/main/packages/zoboz-bam/src/package_json_verifier/README.md
+      Peer dependency `react` has no counterpart in `devDependencies`, so it isn't installed while developing and testing the package.
+
+      --- a/package.json
+      +++ b/package.json
+      @@ -2,7 +2,6 @@
+       {
+         "name": "test",
+         "version": "1.0.0",
+      -  "type": "module",
+         "main": "dist/index.js",
+         "files": ["dist"],
+         "license": "MIT",
+      @@ -10,5 +9,8 @@
+         "keywords": ["a"],
+         "peerDependencies": {
+           "react": "^18.0.0"
+      +  },
+      +  "devDependencies": {
+      +    "react": "^18.0.0"
+         }
+       }
+      """
+    And the JSON content for "package.json" should be:
+      """
+      {
+        "name": "test",
+        "version": "1.0.0",
+        "type": "module",
+        "main": "dist/index.js",
+        "files": ["dist"],
+        "license": "MIT",
+        "author": "someone",
+        "keywords": ["a"],
+        "peerDependencies": {
+          "react": "^18.0.0"
+        }
+      }
+      """
+
+  Scenario: When --diff is given but nothing can be fixed automatically, only the problems are reported
+    Given there is an npm package with:
+      """
+      {
+        "name": "test",
+        "version": "1.0.0",
+        "bin": "dist/cli.js"
+      }
+      """
+    When the following command is executed:
+      """
+      verify-package-json --absolute-package-dir $scenario_dir --diff
+      """
+    Then the result is error and equals the following text:
+      """
+      Bin `test` points to `dist/cli.js`, which does not exist.
+      """
@@ -0,0 +1,197 @@
+Feature: Keep the formatting of package.json when fixing it
+
+  Scenario: When fixes are applied, the indentation, inline arrays and the trailing newline are kept
+    Given there is an npm package with:
+      """
+      {
+          "name": "test",
+          "version": "1.0.0",
+          "type": "commonjs",
+          "files": ["dist"],
+          "sideEffects": ["*.css"],
+          "peerDependencies": {
+              "react": "^18.0.0"
+          },
+          "devDependencies": {
+              "react": "^18.2.0"
+          }
+      }
+      """
+    And there is a file named "dist/setup.js" with:
+      """
+      setup();
+      """
+    When the following command is executed:
+      """
+      verify-package-json --absolute-package-dir $scenario_dir --absolute-output-dir $scenario_dir/dist --can-update-package-json
+      """
+    Then the result is ok
+    And the exact content for "package.json" should be:
+      """
+      {
+          "name": "test",
+          "version": "1.0.0",
+          "files": ["dist"],
+          "sideEffects": ["*.css", "./dist/setup.js"],
+          "peerDependencies": {
+              "react": "^18.0.0"
+          },
+          "devDependencies": {
+              "react": "^18.2.0"
+          }
+      }
+      """
+
+  Scenario: When package.json is indented with tabs, fixes are indented with tabs too
+    Given there is an npm package with the bytes:
+      """
+      {
+      \t"name": "test",
+      \t"version": "1.0.0",
+      \t"peerDependencies": {
+      \t\t"react": "^18.0.0"
+      \t}
+      }
+      """
+    When the following command is executed:
+      """
+      verify-package-json --absolute-package-dir $scenario_dir --can-update-package-json
+      """
+    Then the result is ok
+    And the bytes of "package.json" should be:
+      """
+      {
+      \t"name": "test",
+      \t"version": "1.0.0",
+      \t"peerDependencies": {
+      \t\t"react": "^18.0.0"
+      \t},
+      \t"devDependencies": {
+      \t\t"react": "^18.0.0"
+      \t}
+      }
+      """
+
+  Scenario: When package.json has CRLF line endings, fixes use them too
+    Given there is an npm package with the bytes:
+      """
+      {\r
+        "name": "test",\r
+        "version": "1.0.0",\r
+        "peerDependencies": {\r
+          "react": "^18.0.0"\r
+        }\r
+      }\r
+      """
+    When the following command is executed:
+      """
+      verify-package-json --absolute-package-dir $scenario_dir --can-update-package-json
+      """
+    Then the result is ok
+    And the bytes of "package.json" should be:
+      """
+      {\r
+        "name": "test",\r
+        "version": "1.0.0",\r
+        "peerDependencies": {\r
+          "react": "^18.0.0"\r
+        },\r
+        "devDependencies": {\r
+          "react": "^18.0.0"\r
+        }\r
+      }\r
+      """
+
+  Scenario: When package.json has no trailing newline, fixes don't add one
+    Given there is an npm package with the bytes:
+      """
+      {
+        "name": "test",
+        "version": "1.0.0",
+        "peerDependencies": {
+          "react": "^18.0.0"
+        }
+      }
+      \ No newline at end of file
+      """
+    When the following command is executed:
+      """
+      verify-package-json --absolute-package-dir $scenario_dir --can-update-package-json
+      """
+    Then the result is ok
+    And the bytes of "package.json" should be:
+      """
+      {
+        "name": "test",
+        "version": "1.0.0",
+        "peerDependencies": {
+          "react": "^18.0.0"
+        },
+        "devDependencies": {
+          "react": "^18.0.0"
+        }
+      }
+      \ No newline at end of file
+      """
+
+  Scenario: When the sort-package-json key order is chosen, new keys are inserted where it would put them
+    Given there is an npm package with:
+      """
+      {
+        "name": "test",
+        "version": "1.0.0",
+        "main": "./index.js",
+        "peerDependencies": {
+          "zod": "^3.0.0",
+          "@scope/peer": "^2.0.0"
+        },
+        "engines": {
+          "node": ">=18"
+        }
+      }
+      """
+    And there is a file named "index.js" with:
+      """
+      module.exports = {};
+      """
+    When the following command is executed:
+      """
+      verify-package-json --absolute-package-dir $scenario_dir --can-update-package-json --key-order sort-package-json
+      """
+    Then the result is ok
+    And the exact content for "package.json" should be:
+      """
+      {
+        "name": "test",
+        "version": "1.0.0",
+        "main": "./index.js",
+        "devDependencies": {
+          "@scope/peer": "^2.0.0",
+          "zod": "^3.0.0"
+        },
+        "peerDependencies": {
+          "zod": "^3.0.0",
+          "@scope/peer": "^2.0.0"
+        },
+        "engines": {
+          "node": ">=18"
+        }
+      }
+      """
+
+  Scenario: When an unknown key order is given, the result is error
+    Given there is an npm package with:
+      """
+      {
+        "name": "test",
+        "version": "1.0.0"
+      }
+      """
+    When the following command is executed:
+      """
+      verify-package-json --absolute-package-dir $scenario_dir --key-order alphabetical
+      """
+    Then the result is error and equals the following text:
+      """
+      Invalid key order 'alphabetical'; use `append` or `sort-package-json`
+      """
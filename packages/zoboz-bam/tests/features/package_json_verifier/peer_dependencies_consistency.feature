@@ -0,0 +1,122 @@
+Feature: Ensure peer dependencies are developed against a version consumers can use
+
+  Scenario: When dev versions satisfy their peer ranges, in validate-mode, no change is requested
+    Given there is an npm package with:
+      """
+      {
+        "name": "test",
+        "version": "1.0.0",
+        "devDependencies": {
+          "react": "^18.2.0",
+          "typescript": "~5.4.2",
+          "@scope/local": "workspace:*"
+        },
+        "peerDependencies": {
+          "react": "^17.0.0 || ^18.0.0",
+          "typescript": ">=4.7",
+          "@scope/local": "^1.0.0"
+        },
+        "peerDependenciesMeta": {
+          "typescript": {
+            "optional": true
+          }
+        }
+      }
+      """
+    When the following command is executed:
+      """
+      verify-package-json --absolute-package-dir $scenario_dir
+      """
+    Then the result is ok
+
+  Scenario: When a dev version falls outside its peer range or a meta entry has no peer, in validate-mode, they are reported
+    Given there is an npm package with:
+      """
+      {
+        "name": "test",
+        "version": "1.0.0",
+        "devDependencies": {
+          "react": "^16.14.0",
+          "vue": "3.x"
+        },
+        "peerDependencies": {
+          "react": "^17.0.0 || ^18.0.0",
+          "vue": "^3.2.0",
+          "svelte": "^4.0.0"
+        },
+        "peerDependenciesMeta": {
+          "preact": {
+            "optional": true
+          }
+        }
+      }
+      """
+    When the following command is executed:
+      """
+      verify-package-json --absolute-package-dir $scenario_dir
+      """
+    Then the result is error and equals the following text:
+      """
+      Dev dependency `react` is `^16.14.0`, which allows `16.14.0` outside of its peer range `^17.0.0 || ^18.0.0`; tests could run against a version consumers can't use.
+      Peer dependency `svelte` has no counterpart in `devDependencies`, so it isn't installed while developing and testing the package.
+      Dev dependency `vue` is `3.x`, which allows `3.0.0` outside of its peer range `^3.2.0`; tests could run against a version consumers can't use.
+      warn: `peerDependenciesMeta` describes `preact`, which is not listed in `peerDependencies`.
+      """
+
+  Scenario: When a dev range reaches past its peer range, in validate-mode, it is reported
+    Given there is an npm package with:
+      """
+      {
+        "name": "test",
+        "version": "1.0.0",
+        "devDependencies": {
+          "react": ">=17.0.0",
+          "typescript": "^5.4.0",
+          "vue": "^3.2.0"
+        },
+        "peerDependencies": {
+          "react": "^17.0.0",
+          "typescript": "^5.0.0 || ^6.0.0",
+          "vue": "~3.2.0"
+        }
+      }
+      """
+    When the following command is executed:
+      """
+      verify-package-json --absolute-package-dir $scenario_dir
+      """
+    Then the result is error and equals the following text:
+      """
+      Dev dependency `react` is `>=17.0.0`, which allows `18.0.0` outside of its peer range `^17.0.0`; tests could run against a version consumers can't use.
+      Dev dependency `vue` is `^3.2.0`, which allows `3.3.0` outside of its peer range `~3.2.0`; tests could run against a version consumers can't use.
+      """
+
+  Scenario: When a peer dependency has no dev counterpart, in fix-mode, it gets added to devDependencies
+    Given there is an npm package with:
+      """
+      {
+        "name": "test",
+        "version": "1.0.0",
+        "peerDependencies": {
+          "@scope/peer": "^2.0.0"
+        }
+      }
+      """
+    When the following command is executed:
+      """
+      verify-package-json --absolute-package-dir $scenario_dir --can-update-package-json
+      """
+    Then the result is ok
+    And the JSON content for "package.json" should be:
+      """
+      {
+        "name": "test",
+        "version": "1.0.0",
+        "peerDependencies": {
+          "@scope/peer": "^2.0.0"
+        },
+        "devDependencies": {
+          "@scope/peer": "^2.0.0"
+        }
+      }
+      """
@@ -0,0 +1,212 @@
+Feature: Ensure every exported and referenced file gets published
+
+  Scenario: When "files" lists every exported directory, in validate-mode, no change is requested
+    Given there is an npm package with:
+      """
+      {
+        "name": "test",
+        "version": "1.0.0",
+        "main": "./dist/cjs/index.js",
+        "files": ["dist/", "!dist/**/*.map"],
+        "exports": {
+          ".": {
+            "types": "./dist/dts/index.d.ts",
+            "require": "./dist/cjs/index.js"
+          }
+        }
+      }
+      """
+    And there is a file named "dist/dts/index.d.ts" with:
+      """
+      export { foo } from './foo.js';
+      """
+    And there is a file named "dist/dts/foo.d.ts" with:
+      """
+      export declare const foo: string;
+      """
+    And there is a file named "dist/cjs/index.js" with:
+      """
+      module.exports.foo = require('./foo.js').foo;
+      """
+    And there is a file named "dist/cjs/foo.js" with:
+      """
+      module.exports.foo = 'foo';
+      """
+    And there is a file named "dist/cjs/foo.js.map" with:
+      """
+      {}
+      """
+    When the following command is executed:
+      """
+      verify-package-json --absolute-package-dir $scenario_dir
+      """
+    Then the result is ok
+
+  Scenario: When "files" misses an exported directory, in validate-mode, every unpublished target and import is reported
+    Given there is an npm package with:
+      """
+      {
+        "name": "test",
+        "version": "1.0.0",
+        "files": ["dist/esm", "dist/dts"],
+        "exports": {
+          ".": {
+            "types": "./dist/dts/index.d.ts",
+            "require": "./dist/cjs/index.js",
+            "import": "./dist/esm/index.js"
+          }
+        }
+      }
+      """
+    And there is a file named "dist/dts/index.d.ts" with:
+      """
+      export declare const foo: string;
+      """
+    And there is a file named "dist/cjs/index.js" with:
+      """
+      module.exports.foo = require('./foo.js').foo;
+      """
+    And there is a file named "dist/cjs/foo.js" with:
+      """
+      module.exports.foo = 'foo';
+      """
+    And there is a file named "dist/esm/index.js" with:
+      """
+      export const foo = 'foo';
+      """
+    When the following command is executed:
+      """
+      verify-package-json --absolute-package-dir $scenario_dir
+      """
+    Then the result is error and equals the following text:
+      """
+      File `dist/cjs/index.js`, the target of `exports["."]["require"]`, is not included in the published package; add it to `files` or stop ignoring it.
+      File `dist/cjs/foo.js`, imported by `dist/cjs/index.js`, is not included in the published package; add it to `files` or stop ignoring it.
+      """
+
+  Scenario: When "files" has entries without a slash, in validate-mode, they match at any depth
+    Given there is an npm package with:
+      """
+      {
+        "name": "test",
+        "version": "1.0.0",
+        "files": ["*.d.ts", "cjs", "esm/index.js"],
+        "exports": {
+          ".": {
+            "types": "./dist/dts/index.d.ts",
+            "require": "./dist/cjs/index.js",
+            "import": "./dist/esm/index.js"
+          }
+        }
+      }
+      """
+    And there is a file named "dist/dts/index.d.ts" with:
+      """
+      export declare const foo: string;
+      """
+    And there is a file named "dist/cjs/index.js" with:
+      """
+      module.exports.foo = 'foo';
+      """
+    And there is a file named "dist/esm/index.js" with:
+      """
+      export const foo = 'foo';
+      """
+    When the following command is executed:
+      """
+      verify-package-json --absolute-package-dir $scenario_dir
+      """
+    Then the result is error and equals the following text:
+      """
+      File `dist/esm/index.js`, the target of `exports["."]["import"]`, is not included in the published package; add it to `files` or stop ignoring it.
+      """
+
+  Scenario: When ignore files exclude imported files, in validate-mode, they are reported
+    Given there is an npm package with:
+      """
+      {
+        "name": "test",
+        "version": "1.0.0",
+        "exports": {
+          ".": {
+            "types": "./dist/dts/index.d.ts",
+            "import": "./dist/esm/index.js"
+          }
+        }
+      }
+      """
+    And there is a file named ".gitignore" with:
+      """
+      node_modules
+      """
+    And there is a file named ".npmignore" with:
+      """
+      internal/
+      """
+    And there is a file named "dist/dts/.npmignore" with:
+      """
+      *.d.ts
+      !index.d.ts
+      """
+    And there is a file named "dist/dts/index.d.ts" with:
+      """
+      export { helper } from './internal/helper.js';
+      export { util } from './util.js';
+      """
+    And there is a file named "dist/dts/internal/helper.d.ts" with:
+      """
+      export declare const helper: string;
+      """
+    And there is a file named "dist/dts/util.d.ts" with:
+      """
+      export declare const util: string;
+      """
+    And there is a file named "dist/esm/index.js" with:
+      """
+      export { helper } from './internal/helper.js';
+      export { util } from "./util.js";
+      """
+    And there is a file named "dist/esm/internal/helper.js" with:
+      """
+      export const helper = 'helper';
+      """
+    And there is a file named "dist/esm/util.js" with:
+      """
+      export const util = 'util';
+      """
+    When the following command is executed:
+      """
+      verify-package-json --absolute-package-dir $scenario_dir
+      """
+    Then the result is error and equals the following text:
+      """
+      File `dist/dts/internal/helper.d.ts`, imported by `dist/dts/index.d.ts`, is not included in the published package; add it to `files` or stop ignoring it.
+      File `dist/dts/util.d.ts`, imported by `dist/dts/index.d.ts`, is not included in the published package; add it to `files` or stop ignoring it.
+      File `dist/esm/internal/helper.js`, imported by `dist/esm/index.js`, is not included in the published package; add it to `files` or stop ignoring it.
+      """
+
+  Scenario: When there is no .npmignore, .gitignore is used instead
+    Given there is an npm package with:
+      """
+      {
+        "name": "test",
+        "version": "1.0.0",
+        "exports": "./dist/index.js"
+      }
+      """
+    And there is a file named ".gitignore" with:
+      """
+      /dist
+      """
+    And there is a file named "dist/index.js" with:
+      """
+      module.exports = {};
+      """
+    When the following command is executed:
+      """
+      verify-package-json --absolute-package-dir $scenario_dir
+      """
+    Then the result is error and equals the following text:
+      """
+      File `dist/index.js`, the target of `exports`, is not included in the published package; add it to `files` or stop ignoring it.
+      """
@@ -0,0 +1,125 @@
+Feature: Report verifier diagnostics as SARIF or GitHub Actions annotations
+
+  Scenario: When --reporter github is given, every diagnostic is reported as an annotation on package.json
+    Given there is an npm package with:
+      """
+      {
+        "name": "test",
+        "version": "1.0.0",
+        "type": "module",
+        "peerDependenciesMeta": {
+          "react": {
+            "optional": true
+          }
+        }
+      }
+      """
+    When the following command is executed:
+      """
+      verify-package-json --absolute-package-dir $scenario_dir --reporter github
+      """
+    Then the result is error and equals the following text:
+      """
+      ::error file=$scenario_dir/package.json,line=5,col=3,title=type-field-remover::Field `type` in package.json should not exist. https://github.com/dariushalipour/zoboz/blob/main/packages/zoboz-bam/src/package_json_verifier/README.md
+      ::warning file=$scenario_dir/package.json,line=7,col=5,title=peer-dependencies-meta-consistency::`peerDependenciesMeta` describes `react`, which is not listed in `peerDependencies`.
+      """
+
+  Scenario: When --report-file is given along with --reporter sarif, the report is written to that file
+    Given there is an npm package with:
+      """
+      {
+        "name": "test",
+        "version": "1.0.0",
+        "type": "module"
+      }
+      """
+    When the following command is executed:
+      """
+      verify-package-json --absolute-package-dir $scenario_dir --reporter sarif --report-file $scenario_dir/report.sarif
+      """
+    Then the result is error and equals the following text:
+      """
+      Field `type` in package.json should not exist. https://github.com/dariushalipour/zoboz/blob/main/packages/zoboz-bam/src/package_json_verifier/README.md
+      """
+    And the report "report.sarif" should be:
+      """
+      {
+        "$schema": "https://json.schemastore.org/sarif-2.1.0.json",
+        "version": "2.1.0",
+        "runs": [
+          {
+            "tool": {
+              "driver": {
+                "name": "zoboz-bam",
+                "informationUri": "https://github.com/dariushalipour/zoboz",
+                "rules": [
+                  {
+                    "id": "type-field-remover"
+                  }
+                ]
+              }
+            },
+            "results": [
+              {
+                "ruleId": "type-field-remover",
+                "level": "error",
+                "message": {
+                  "text": "Field `type` in package.json should not exist. https://github.com/dariushalipour/zoboz/blob/main/packages/zoboz-bam/src/package_json_verifier/README.md"
+                },
+                "locations": [
+                  {
+                    "physicalLocation": {
+                      "artifactLocation": {
+                        "uri": "file://$scenario_dir/package.json"
+                      },
+                      "region": {
+                        "startLine": 5,
+                        "startColumn": 3
+                      }
+                    }
+                  }
+                ]
+              }
+            ]
+          }
+        ]
+      }
+      """
+
+  Scenario: When --reporter github is given, output directory diagnostics are reported at the package's `type` field
+    Given there is an npm package with:
+      """
+      {
+        "name": "test",
+        "version": "1.0.0"
+      }
+      """
+    And there is a file named "dist/esm/index.js" with:
+      """
+      export default 1;
+      """
+    When the following command is executed:
+      """
+      verify-package-json --absolute-package-dir $scenario_dir --absolute-output-dir $scenario_dir/dist/esm --reporter github
+      """
+    Then the result is error and equals the following text:
+      """
+      ::error file=$scenario_dir/package.json,line=2,col=1,title=output-type-markers::Output directory `dist/esm` has ESM `.js` files, but no package.json marker with `"type": "module"`; Node.js would load them as the package's `type` says.
+      """
+
+  Scenario: When an unknown reporter is given, it is an error
+    Given there is an npm package with:
+      """
+      {
+        "name": "test",
+        "version": "1.0.0"
+      }
+      """
+    When the following command is executed:
+      """
+      verify-package-json --absolute-package-dir $scenario_dir --reporter junit
+      """
+    Then the result is error and equals the following text:
+      """
+      Invalid reporter 'junit'; use `sarif` or `github`
+      """
@@ -0,0 +1,193 @@
+Feature: Configure the rules of the package.json verifier
+
+  Scenario: When a rule is turned off by the "zoboz" key of package.json, it is not reported
+    Given there is an npm package with:
+      """
+      {
+        "name": "test",
+        "version": "1.0.0",
+        "type": "module",
+        "zoboz": {
+          "rules": {
+            "type-field-remover": "off"
+          }
+        }
+      }
+      """
+    When the following command is executed:
+      """
+      verify-package-json --absolute-package-dir $scenario_dir --can-update-package-json
+      """
+    Then the result is ok
+    And the JSON content for "package.json" should be:
+      """
+      {
+        "name": "test",
+        "version": "1.0.0",
+        "type": "module",
+        "zoboz": {
+          "rules": {
+            "type-field-remover": "off"
+          }
+        }
+      }
+      """
+
+  Scenario: When a rule is downgraded by zoboz.config.json, it is reported with the new severity
+    Given there is an npm package with:
+      """
+      {
+        "name": "test",
+        "version": "1.0.0",
+        "type": "module",
+        "exports": {
+          ".": "./dist/index.js"
+        }
+      }
+      """
+    And there is a file named "zoboz.config.json" with:
+      """
+      {
+        "rules": {
+          "type-field-remover": "warn"
+        }
+      }
+      """
+    When the following command is executed:
+      """
+      verify-package-json --absolute-package-dir $scenario_dir
+      """
+    Then the result is error and equals the following text:
+      """
+      warn: Field `type` in package.json should not exist. https://github.com/dariushalipour/zoboz/blob/main/packages/zoboz-bam/src/package_json_verifier/README.md
+      Target `./dist/index.js` of `exports["."]` matches no file.
+      """
+
+  Scenario: When rules are given options, they are taken into account
+    Given there is an npm package with:
+      """
+      {
+        "name": "test",
+        "version": "1.0.0",
+        "type": "module",
+        "zoboz": {
+          "rules": {
+            "type-field-remover": ["error", { "allow": ["module"] }],
+            "runtime-dependencies-availability": ["error", { "ignore": ["fsevents"] }]
+          }
+        }
+      }
+      """
+    And there is a file named "dist/index.js" with:
+      """
+      try {
+        require("fsevents");
+      } catch {}
+      """
+    And there is a file named "dist/package.json" with:
+      """
+      {
+        "type": "commonjs"
+      }
+      """
+    When the following command is executed:
+      """
+      verify-package-json --absolute-package-dir $scenario_dir --absolute-output-dir $scenario_dir/dist
+      """
+    Then the result is ok
+
+  Scenario: When an allowed type is not the one in package.json, it is still reported
+    Given there is an npm package with:
+      """
+      {
+        "name": "test",
+        "version": "1.0.0",
+        "type": "commonjs",
+        "zoboz": {
+          "rules": {
+            "type-field-remover": ["error", { "allow": ["module"] }]
+          }
+        }
+      }
+      """
+    When the following command is executed:
+      """
+      verify-package-json --absolute-package-dir $scenario_dir
+      """
+    Then the result is error and equals the following text:
+      """
+      Field `type` in package.json should not exist. https://github.com/dariushalipour/zoboz/blob/main/packages/zoboz-bam/src/package_json_verifier/README.md
+      """
+
+  Scenario: When an unknown rule is configured, it is an error
+    Given there is an npm package with:
+      """
+      {
+        "name": "test",
+        "version": "1.0.0",
+        "zoboz": {
+          "rules": {
+            "no-type-field": "off"
+          }
+        }
+      }
+      """
+    When the following command is executed:
+      """
+      verify-package-json --absolute-package-dir $scenario_dir
+      """
+    Then the result is error and equals the following text:
+      """
+      Unknown rule `no-type-field` in the `zoboz` key of package.json; use one of `type-field-remover`, `runtime-dependencies-availability`, `peer-dependencies-consistency`, `peer-dependencies-meta-consistency`, `dependency-protocols-publishability`, `exports-targets-existence`, `exports-conditions-order`, `published-files-inclusion`, `side-effects-declaration`, `side-effects-tree-shaking`, `bin-entries-validity`, `output-type-markers`
+      """
+
+  Scenario: When a rule is given an option it doesn't take, it is an error
+    Given there is an npm package with:
+      """
+      {
+        "name": "test",
+        "version": "1.0.0"
+      }
+      """
+    And there is a file named "zoboz.config.json" with:
+      """
+      {
+        "rules": {
+          "bin-entries-validity": ["warn", { "allow": ["cli.js"] }]
+        }
+      }
+      """
+    When the following command is executed:
+      """
+      verify-package-json --absolute-package-dir $scenario_dir
+      """
+    Then the result is error and equals the following text:
+      """
+      Rule `bin-entries-validity` takes no options, but got `allow` in zoboz.config.json
+      """
+
+  Scenario: When both package.json and zoboz.config.json configure zoboz, it is an error
+    Given there is an npm package with:
+      """
+      {
+        "name": "test",
+        "version": "1.0.0",
+        "zoboz": {
+          "rules": {}
+        }
+      }
+      """
+    And there is a file named "zoboz.config.json" with:
+      """
+      {
+        "rules": {}
+      }
+      """
+    When the following command is executed:
+      """
+      verify-package-json --absolute-package-dir $scenario_dir
+      """
+    Then the result is error and equals the following text:
+      """
+      zoboz is configured both by the `zoboz` key of package.json and by zoboz.config.json; keep only one of them
+      """
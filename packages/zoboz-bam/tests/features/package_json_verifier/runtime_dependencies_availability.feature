@@ -5,20 +5,317 @@ Feature: Ensure runtime dependencies will be available to the consumers
   or if they want the user to bring their own version,
   it should get added to devDependencies,
   and get duplicated in peerDependencies
+    Given there is an npm package with:
+      """
+      {
+        "name": "test",
+        "version": "1.0.0",
+        "main": "dist/cjs/index.js",
+        "dependencies": {
+          "listed": "^1.0.0"
+        },
+        "devDependencies": {
+          "@scope/peer": "^2.1.0"
+        },
+        "peerDependencies": {
+          "@scope/peer": "^2.0.0"
+        }
+      }
+      """
+    And there is a file named "dist/cjs/index.js" with:
+      """
+      const fs = require('node:fs');
+      const path = require('path');
+      const listed = require('listed/sub/path');
+      const peer = require('@scope/peer');
+      const unlisted = require('unlisted');
+      const self = require('test/other');
+      const local = require('./local.js');
+      """
+    And there is a file named "dist/esm/index.js" with:
+      """
+      import '@scope/polyfill';
+      import { lazy } from "lazy-thing";
+      """
+    And there is a file named "dist/cjs/package.json" with:
+      """
+      {
+        "type": "commonjs"
+      }
+      """
+    And there is a file named "dist/esm/package.json" with:
+      """
+      {
+        "type": "module"
+      }
+      """
+    When the following command is executed:
+      """
+      verify-package-json --absolute-package-dir $scenario_dir --absolute-output-dir $scenario_dir/dist/cjs --absolute-output-dir $scenario_dir/dist/esm
+      """
+    Then the result is error and equals the following text:
+      """
+      Runtime dependency `@scope/polyfill` is not listed in package.json. Add it to `dependencies`, or to both `devDependencies` and `peerDependencies` to let consumers bring their own version.
+      Runtime dependency `lazy-thing` is not listed in package.json. Add it to `dependencies`, or to both `devDependencies` and `peerDependencies` to let consumers bring their own version.
+      Runtime dependency `unlisted` is not listed in package.json. Add it to `dependencies`, or to both `devDependencies` and `peerDependencies` to let consumers bring their own version.
+      """
 
   Scenario: If a runtime dependency is not listed at all, in fix-mode,
-  it will ask the user if they want to force a version,
-  resulting in addding it in dependencies,
-  or if the user wants to let the user bring their own,
-  resulting in adding it in devDependencies as well as peerDependencies
+  it will get added to dependencies with the installed version,
+  or with --prefer-peer-dependencies to let the user bring their own,
+  it will get added to devDependencies as well as peerDependencies
+    Given there is an npm package with:
+      """
+      {
+        "name": "test",
+        "version": "1.0.0",
+        "main": "dist/cjs/index.js"
+      }
+      """
+    And there is a file named "node_modules/unlisted/package.json" with:
+      """
+      {
+        "name": "unlisted",
+        "version": "3.1.4"
+      }
+      """
+    And there is a file named "dist/cjs/index.js" with:
+      """
+      const unlisted = require('unlisted');
+      const missing = require('not-installed');
+      """
+    When the following command is executed:
+      """
+      verify-package-json --absolute-package-dir $scenario_dir --absolute-output-dir $scenario_dir/dist/cjs --can-update-package-json
+      """
+    Then the JSON content for "package.json" should be:
+      """
+      {
+        "name": "test",
+        "version": "1.0.0",
+        "main": "dist/cjs/index.js",
+        "dependencies": {
+          "not-installed": "*",
+          "unlisted": "^3.1.4"
+        }
+      }
+      """
+    When the following command is executed:
+      """
+      verify-package-json --absolute-package-dir $scenario_dir --absolute-output-dir $scenario_dir/dist/cjs
+      """
+    Then the result is ok
+
+  Scenario: If a runtime dependency is not listed at all, in fix-mode with --prefer-peer-dependencies,
+  it will get added to devDependencies as well as peerDependencies
+    Given there is an npm package with:
+      """
+      {
+        "name": "test",
+        "version": "1.0.0",
+        "main": "dist/cjs/index.js"
+      }
+      """
+    And there is a file named "node_modules/unlisted/package.json" with:
+      """
+      {
+        "name": "unlisted",
+        "version": "3.1.4"
+      }
+      """
+    And there is a file named "dist/cjs/index.js" with:
+      """
+      const unlisted = require('unlisted');
+      """
+    When the following command is executed:
+      """
+      verify-package-json --absolute-package-dir $scenario_dir --absolute-output-dir $scenario_dir/dist/cjs --can-update-package-json --prefer-peer-dependencies
+      """
+    Then the JSON content for "package.json" should be:
+      """
+      {
+        "name": "test",
+        "version": "1.0.0",
+        "main": "dist/cjs/index.js",
+        "devDependencies": {
+          "unlisted": "^3.1.4"
+        },
+        "peerDependencies": {
+          "unlisted": "^3.1.4"
+        }
+      }
+      """
 
   Scenario: If a runtime dependency is listed as a devDependency, in validate-mode,
   it will be requested to be moved to dependencies
   or if they want the user to bring their own version,
   it should get duplicated in peerDependencies
+    Given there is an npm package with:
+      """
+      {
+        "name": "test",
+        "version": "1.0.0",
+        "main": "dist/cjs/index.js",
+        "devDependencies": {
+          "dev-only": "^1.2.3"
+        }
+      }
+      """
+    And there is a file named "dist/cjs/index.js" with:
+      """
+      const devOnly = require('dev-only');
+      """
+    And there is a file named "dist/cjs/package.json" with:
+      """
+      {
+        "type": "commonjs"
+      }
+      """
+    When the following command is executed:
+      """
+      verify-package-json --absolute-package-dir $scenario_dir --absolute-output-dir $scenario_dir/dist/cjs
+      """
+    Then the result is error and equals the following text:
+      """
+      Runtime dependency `dev-only` is only listed in `devDependencies`. Move it to `dependencies`, or duplicate it in `peerDependencies` to let consumers bring their own version.
+      """
 
   Scenario: If a runtime dependency is listed as a devDependency, in fix-mode,
-  it will ask the user if they want to force a version,
-  resulting in addding it in dependencies and removing it from devDependencies,
-  or if the user wants to let the user bring their own,
-  resulting in adding it in devDependencies as well as peerDependencies
+  it will get added in dependencies and removed from devDependencies
+    Given there is an npm package with:
+      """
+      {
+        "name": "test",
+        "version": "1.0.0",
+        "main": "dist/cjs/index.js",
+        "devDependencies": {
+          "dev-only": "^1.2.3",
+          "lodash.merge": "^4.6.2",
+          "typescript": "5.7.3"
+        }
+      }
+      """
+    And there is a file named "dist/cjs/index.js" with:
+      """
+      const devOnly = require('dev-only');
+      const merge = require('lodash.merge');
+      """
+    When the following command is executed:
+      """
+      verify-package-json --absolute-package-dir $scenario_dir --absolute-output-dir $scenario_dir/dist/cjs --can-update-package-json
+      """
+    Then the JSON content for "package.json" should be:
+      """
+      {
+        "name": "test",
+        "version": "1.0.0",
+        "main": "dist/cjs/index.js",
+        "devDependencies": {
+          "typescript": "5.7.3"
+        },
+        "dependencies": {
+          "dev-only": "^1.2.3",
+          "lodash.merge": "^4.6.2"
+        }
+      }
+      """
+
+  Scenario: If a runtime dependency is listed as a devDependency, in fix-mode with --prefer-peer-dependencies,
+  it will get duplicated in peerDependencies
+    Given there is an npm package with:
+      """
+      {
+        "name": "test",
+        "version": "1.0.0",
+        "main": "dist/cjs/index.js",
+        "devDependencies": {
+          "dev-only": "^1.2.3"
+        }
+      }
+      """
+    And there is a file named "dist/cjs/index.js" with:
+      """
+      const devOnly = require('dev-only');
+      """
+    When the following command is executed:
+      """
+      verify-package-json --absolute-package-dir $scenario_dir --absolute-output-dir $scenario_dir/dist/cjs --can-update-package-json --prefer-peer-dependencies
+      """
+    Then the JSON content for "package.json" should be:
+      """
+      {
+        "name": "test",
+        "version": "1.0.0",
+        "main": "dist/cjs/index.js",
+        "devDependencies": {
+          "dev-only": "^1.2.3"
+        },
+        "peerDependencies": {
+          "dev-only": "^1.2.3"
+        }
+      }
+      """
+
+  Scenario: If another field of package.json has the wrong type, the dependencies are still read
+    Given there is an npm package with:
+      """
+      {
+        "name": "test",
+        "version": "1.0.0",
+        "files": "dist",
+        "dependencies": {
+          "listed": "^1.0.0"
+        }
+      }
+      """
+    And there is a file named "dist/cjs/index.js" with:
+      """
+      const listed = require('listed');
+      const unlisted = require('unlisted');
+      """
+    And there is a file named "dist/cjs/package.json" with:
+      """
+      {
+        "type": "commonjs"
+      }
+      """
+    When the following command is executed:
+      """
+      verify-package-json --absolute-package-dir $scenario_dir --absolute-output-dir $scenario_dir/dist/cjs
+      """
+    Then the result is error and equals the following text:
+      """
+      Runtime dependency `unlisted` is not listed in package.json. Add it to `dependencies`, or to both `devDependencies` and `peerDependencies` to let consumers bring their own version.
+      """
+
+  Scenario: If a dependency has a range of the wrong type, the other dependencies are still read
+    Given there is an npm package with:
+      """
+      {
+        "name": "test",
+        "version": "1.0.0",
+        "dependencies": {
+          "listed": "^1.0.0",
+          "broken": 1
+        }
+      }
+      """
+    And there is a file named "dist/cjs/index.js" with:
+      """
+      const listed = require('listed');
+      const unlisted = require('unlisted');
+      """
+    And there is a file named "dist/cjs/package.json" with:
+      """
+      {
+        "type": "commonjs"
+      }
+      """
+    When the following command is executed:
+      """
+      verify-package-json --absolute-package-dir $scenario_dir --absolute-output-dir $scenario_dir/dist/cjs
+      """
+    Then the result is error and equals the following text:
+      """
+      Runtime dependency `unlisted` is not listed in package.json. Add it to `dependencies`, or to both `devDependencies` and `peerDependencies` to let consumers bring their own version.
+      """
@@ -0,0 +1,138 @@
+Feature: Ensure "sideEffects" matches the side effects found in the output
+
+  Scenario: When "sideEffects" is false but some modules have side effects, in validate-mode, they are reported
+    Given there is an npm package with:
+      """
+      {
+        "name": "test",
+        "version": "1.0.0",
+        "sideEffects": false
+      }
+      """
+    And there is a file named "dist/esm/index.js" with:
+      """
+      import './polyfill.js';
+      import './style.css';
+      import { helper } from './helper.js';
+
+      export function run() {
+        helper();
+        window.ran = true;
+      }
+      """
+    And there is a file named "dist/esm/polyfill.js" with:
+      """
+      globalThis.structuredClone = globalThis.structuredClone || function (value) {
+        return JSON.parse(JSON.stringify(value));
+      };
+      """
+    And there is a file named "dist/esm/helper.js" with:
+      """
+      export const helper = () => {};
+      """
+    And there is a file named "dist/esm/style.css" with:
+      """
+      body { margin: 0; }
+      """
+    And there is a file named "dist/esm/register.js" with:
+      """
+      import 'reflect-metadata';
+      """
+    And there is a file named "dist/esm/package.json" with:
+      """
+      {
+        "type": "module"
+      }
+      """
+    When the following command is executed:
+      """
+      verify-package-json --absolute-package-dir $scenario_dir --absolute-output-dir $scenario_dir/dist/esm
+      """
+    Then the result is error and equals the following text:
+      """
+      Runtime dependency `reflect-metadata` is not listed in package.json. Add it to `dependencies`, or to both `devDependencies` and `peerDependencies` to let consumers bring their own version.
+      File `dist/esm/polyfill.js` has an assignment to `globalThis.structuredClone`, but `sideEffects` marks it as side-effect free; list it in `sideEffects`.
+      File `dist/esm/register.js` has a side-effect import of `reflect-metadata`, but `sideEffects` marks it as side-effect free; list it in `sideEffects`.
+      File `dist/esm/style.css` is imported for its side effects by `dist/esm/index.js`, but `sideEffects` marks it as side-effect free; list it in `sideEffects`.
+      """
+
+  Scenario: When "sideEffects" misses a module with side effects, in fix-mode, it gets listed
+    Given there is an npm package with:
+      """
+      {
+        "name": "test",
+        "version": "1.0.0",
+        "sideEffects": ["*.css"]
+      }
+      """
+    And there is a file named "dist/cjs/index.js" with:
+      """
+      "use strict";
+      Object.defineProperty(exports, "__esModule", { value: true });
+      require("./style.css");
+      __exportStar(require("./setup"), exports);
+      """
+    And there is a file named "dist/cjs/style.css" with:
+      """
+      body { margin: 0; }
+      """
+    And there is a file named "dist/cjs/setup.js" with:
+      """
+      "use strict";
+      const registry_1 = require("./registry");
+      (0, registry_1.register)("default");
+      """
+    And there is a file named "dist/cjs/registry.js" with:
+      """
+      exports.register = function (name) {
+        console.log(name);
+      };
+      """
+    When the following command is executed:
+      """
+      verify-package-json --absolute-package-dir $scenario_dir --absolute-output-dir $scenario_dir/dist/cjs --can-update-package-json
+      """
+    Then the result is ok
+    And the JSON content for "package.json" should be:
+      """
+      {
+        "name": "test",
+        "version": "1.0.0",
+        "sideEffects": ["*.css", "./dist/cjs/setup.js"]
+      }
+      """
+
+  Scenario: When no module has side effects but "sideEffects" is true, in fix-mode, it is set to false
+    Given there is an npm package with:
+      """
+      {
+        "name": "test",
+        "version": "1.0.0",
+        "sideEffects": true
+      }
+      """
+    And there is a file named "dist/esm/index.js" with:
+      """
+      import { helper } from './helper.js';
+
+      export const run = () => helper();
+      """
+    And there is a file named "dist/esm/helper.js" with:
+      """
+      export function helper() {
+        console.log('helper');
+      }
+      """
+    When the following command is executed:
+      """
+      verify-package-json --absolute-package-dir $scenario_dir --absolute-output-dir $scenario_dir/dist/esm --can-update-package-json
+      """
+    Then the result is ok
+    And the JSON content for "package.json" should be:
+      """
+      {
+        "name": "test",
+        "version": "1.0.0",
+        "sideEffects": false
+      }
+      """
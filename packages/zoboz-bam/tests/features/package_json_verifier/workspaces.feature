@@ -0,0 +1,174 @@
+Feature: Verify every package of a workspace in one invocation
+
+  Scenario: When the root package.json lists workspaces, every matching package is verified and reported under its path
+    Given there is an npm package with:
+      """
+      {
+        "name": "monorepo",
+        "private": true,
+        "workspaces": ["packages/*", "!packages/internal"]
+      }
+      """
+    And there is a file named "packages/a/package.json" with:
+      """
+      {
+        "name": "a",
+        "version": "1.0.0",
+        "type": "module"
+      }
+      """
+    And there is a file named "packages/a/dist/index.js" with:
+      """
+      import leftPad from 'left-pad';
+      """
+    And there is a file named "packages/a/dist/package.json" with:
+      """
+      {
+        "type": "module"
+      }
+      """
+    And there is a file named "packages/b/package.json" with:
+      """
+      {
+        "name": "b",
+        "version": "1.0.0"
+      }
+      """
+    And there is a file named "packages/internal/package.json" with:
+      """
+      {
+        "name": "internal",
+        "version": "1.0.0",
+        "type": "module"
+      }
+      """
+    When the following command is executed:
+      """
+      verify-package-json --workspace-root $scenario_dir --relative-output-dir dist
+      """
+    Then the result is error and equals the following text:
+      """
+      packages/a
+        Field `type` in package.json should not exist. https://github.com/dariushalipour/zoboz/blob/main/packages/zoboz-bam/src/package_json_verifier/README.md
+        Runtime dependency `left-pad` is not listed in package.json. Add it to `dependencies`, or to both `devDependencies` and `peerDependencies` to let consumers bring their own version.
+      """
+
+  Scenario: When there is a pnpm-workspace.yaml, its packages are verified
+    Given there is an npm package with:
+      """
+      {
+        "name": "monorepo",
+        "private": true
+      }
+      """
+    And there is a file named "pnpm-workspace.yaml" with:
+      """
+      packages:
+        # every app
+        - 'apps/*'
+        - "libs/**"
+      """
+    And there is a file named "apps/web/package.json" with:
+      """
+      {
+        "name": "web",
+        "version": "1.0.0",
+        "type": "commonjs"
+      }
+      """
+    And there is a file named "libs/ui/core/package.json" with:
+      """
+      {
+        "name": "@libs/ui-core",
+        "version": "1.0.0",
+        "type": "module",
+        "peerDependenciesMeta": {
+          "react": {
+            "optional": true
+          }
+        }
+      }
+      """
+    When the following command is executed:
+      """
+      verify-package-json --workspace-root $scenario_dir --format json
+      """
+    Then the result is error and equals the following text:
+      """
+      {
+        "apps/web": [
+          {
+            "ruleId": "type-field-remover",
+            "severity": "error",
+            "jsonPath": "/type",
+            "message": "Field `type` in package.json should not exist. https://github.com/dariushalipour/zoboz/blob/main/packages/zoboz-bam/src/package_json_verifier/README.md",
+            "fixable": true
+          }
+        ],
+        "libs/ui/core": [
+          {
+            "ruleId": "type-field-remover",
+            "severity": "error",
+            "jsonPath": "/type",
+            "message": "Field `type` in package.json should not exist. https://github.com/dariushalipour/zoboz/blob/main/packages/zoboz-bam/src/package_json_verifier/README.md",
+            "fixable": true
+          },
+          {
+            "ruleId": "peer-dependencies-meta-consistency",
+            "severity": "warn",
+            "jsonPath": "/peerDependenciesMeta/react",
+            "message": "`peerDependenciesMeta` describes `react`, which is not listed in `peerDependencies`.",
+            "fixable": false
+          }
+        ]
+      }
+      """
+
+  Scenario: When workspace packages have fixable issues, in fix-mode, each of them gets fixed
+    Given there is an npm package with:
+      """
+      {
+        "name": "monorepo",
+        "private": true,
+        "workspaces": {
+          "packages": ["./packages/*/"]
+        }
+      }
+      """
+    And there is a file named "packages/a/package.json" with:
+      """
+      {
+        "name": "a",
+        "version": "1.0.0",
+        "type": "module"
+      }
+      """
+    When the following command is executed:
+      """
+      verify-package-json --workspace-root $scenario_dir --can-update-package-json
+      """
+    Then the result is ok
+    And the JSON content for "packages/a/package.json" should be:
+      """
+      {
+        "name": "a",
+        "version": "1.0.0"
+      }
+      """
+
+  Scenario: When the workspace root declares no workspaces, it is an error
+    Given there is an npm package with:
+      """
+      {
+        "name": "monorepo",
+        "private": true
+      }
+      """
+    When the following command is executed:
+      """
+      verify-package-json --workspace-root $scenario_dir
+      """
+    Then the result is error and equals the following text:
+      """
+      No workspaces found in $scenario_dir; list them in the `workspaces` field of package.json or in pnpm-workspace.yaml
+      """
@@ -0,0 +1,175 @@
+Feature: Resolve every export entry the way consumers would
+
+  Scenario: When every entry resolves for runtime and types, in all modes by default, the result is ok
+    Given there is an npm package with:
+      """
+      {
+        "name": "test",
+        "version": "1.0.0",
+        "main": "./dist/cjs/index.js",
+        "types": "./dist/cjs/index.d.ts",
+        "exports": {
+          ".": {
+            "import": {
+              "types": "./dist/esm/index.d.mts",
+              "default": "./dist/esm/index.mjs"
+            },
+            "require": {
+              "types": "./dist/cjs/index.d.ts",
+              "default": "./dist/cjs/index.js"
+            }
+          },
+          "./package.json": "./package.json"
+        }
+      }
+      """
+    And there is a file named "dist/esm/index.d.mts" with:
+      """
+      export declare const foo: string;
+      """
+    And there is a file named "dist/esm/index.mjs" with:
+      """
+      export const foo = 'foo';
+      """
+    And there is a file named "dist/cjs/index.d.ts" with:
+      """
+      export declare const foo: string;
+      """
+    And there is a file named "dist/cjs/index.js" with:
+      """
+      module.exports.foo = 'foo';
+      """
+    When the following command is executed:
+      """
+      verify-resolution --absolute-package-dir $scenario_dir
+      """
+    Then the result is ok
+
+  Scenario: When types are only reachable through "import", the require-based modes report it
+    Given there is an npm package with:
+      """
+      {
+        "name": "test",
+        "version": "1.0.0",
+        "exports": {
+          ".": {
+            "import": {
+              "types": "./dist/esm/index.d.ts",
+              "default": "./dist/esm/index.js"
+            },
+            "require": "./dist/cjs/index.js"
+          },
+          "./feature": {
+            "types": "./dist/esm/feature.d.ts",
+            "import": "./dist/esm/feature.js"
+          }
+        }
+      }
+      """
+    And there is a file named "dist/esm/package.json" with:
+      """
+      {
+        "type": "module"
+      }
+      """
+    And there is a file named "dist/esm/index.d.ts" with:
+      """
+      export declare const foo: string;
+      """
+    And there is a file named "dist/esm/index.js" with:
+      """
+      export const foo = 'foo';
+      """
+    And there is a file named "dist/cjs/index.js" with:
+      """
+      module.exports.foo = 'foo';
+      """
+    And there is a file named "dist/esm/feature.d.ts" with:
+      """
+      export declare const feature: string;
+      """
+    And there is a file named "dist/esm/feature.js" with:
+      """
+      export const feature = 'feature';
+      """
+    When the following command is executed:
+      """
+      verify-resolution --absolute-package-dir $scenario_dir --modes node16-cjs,node16-esm,bundler
+      """
+    Then the result is error and equals the following text:
+      """
+      Subpath    node16-cjs  node16-esm  bundler
+      .          mismatch    pass        pass
+      ./feature  mismatch    pass        pass
+
+      `.` under node16-cjs: runtime resolves to `./dist/cjs/index.js` but types fail to resolve
+      `./feature` under node16-cjs: types resolve to `./dist/esm/feature.d.ts` but runtime fails to resolve
+      """
+
+  Scenario: When declarations don't share the format of the runtime files, node16 modes report masquerading
+    Given there is an npm package with:
+      """
+      {
+        "name": "test",
+        "version": "1.0.0",
+        "exports": {
+          ".": {
+            "types": "./dist/dts/index.d.ts",
+            "require": "./dist/cjs/index.js",
+            "import": "./dist/esm/index.js"
+          }
+        }
+      }
+      """
+    And there is a file named "dist/dts/index.d.ts" with:
+      """
+      export declare const foo: string;
+      """
+    And there is a file named "dist/cjs/index.js" with:
+      """
+      module.exports.foo = 'foo';
+      """
+    And there is a file named "dist/esm/index.js" with:
+      """
+      export const foo = 'foo';
+      """
+    When the following command is executed:
+      """
+      verify-resolution --absolute-package-dir $scenario_dir --modes node16-cjs,node16-esm,bundler
+      """
+    Then the result is error and equals the following text:
+      """
+      Subpath  node16-cjs  node16-esm  bundler
+      .        pass        mismatch    pass
+
+      `.` under node16-esm: types `./dist/dts/index.d.ts` are CJS but runtime `./dist/esm/index.js` is ESM (masquerading as CJS)
+      """
+
+  Scenario: When every entry resolves in the requested modes, the result is ok
+    Given there is an npm package with:
+      """
+      {
+        "name": "test",
+        "version": "1.0.0",
+        "type": "module",
+        "exports": {
+          ".": {
+            "types": "./dist/dts/index.d.ts",
+            "import": "./dist/esm/index.js"
+          }
+        }
+      }
+      """
+    And there is a file named "dist/dts/index.d.ts" with:
+      """
+      export declare const foo: string;
+      """
+    And there is a file named "dist/esm/index.js" with:
+      """
+      export const foo = 'foo';
+      """
+    When the following command is executed:
+      """
+      verify-resolution --absolute-package-dir $scenario_dir --modes node16-esm,bundler
+      """
+    Then the result is ok
@@ -0,0 +1,43 @@
+Feature: Report specifiers that the reformatter couldn't settle
+
+  Scenario: Unresolved and ambiguous relative specifiers are reported as GitHub Actions annotations
+    Given there is an npm package with:
+      """
+      {
+        "name": "test",
+        "version": "1.0.0",
+        "module": "dist/esm/index.js"
+      }
+      """
+    And the package has a directory named "src"
+    And the package has a directory named "dist/esm"
+    And there is a file named "dist/esm/index.js" with:
+      """
+      import foo from './foo';
+      import { missing } from "./missing";
+      import leftPad from 'left-pad';
+      """
+    And there is a file named "dist/esm/foo.js" with:
+      """
+      export default 'foo';
+      """
+    And there is a file named "dist/esm/foo/index.js" with:
+      """
+      export default 'foo/index';
+      """
+    When the following command is executed:
+      """
+      reformat-specifiers --output-format esm --absolute-package-dir $scenario_dir --absolute-source-dir $scenario_dir/src --absolute-output-dir $scenario_dir/dist/esm --reporter github --report-file $scenario_dir/report.txt
+      """
+    Then the result is ok
+    And the JS content for "dist/esm/index.js" should be:
+      """
+      import foo from './foo.js';
+      import { missing } from "./missing";
+      import leftPad from 'left-pad';
+      """
+    And the report "report.txt" should be:
+      """
+      ::warning file=$scenario_dir/dist/esm/index.js,line=2,col=18,title=ambiguous-specifier::Specifier `./foo` matches more than one module (`./foo.js`, `./foo/index.js`); it was rewritten to `./foo.js`.
+      ::warning file=$scenario_dir/dist/esm/index.js,line=3,col=26,title=unresolved-specifier::Specifier `./missing` doesn't resolve to any file, so it was left as is.
+      """
@@ -0,0 +1,94 @@
+Feature: Write package.json type markers into the output directories
+
+  Scenario: Each output directory gets the marker of its format
+    Given there is an npm package with:
+      """
+      {
+        "name": "test",
+        "version": "1.0.0",
+        "type": "module"
+      }
+      """
+    And the package has a directory named "dist/esm"
+    And the package has a directory named "dist/cjs"
+    And there is a file named "dist/cjs/package.json" with:
+      """
+      {
+        "type": "module"
+      }
+      """
+    When the following command is executed:
+      """
+      enforce-type --absolute-package-dir $scenario_dir --absolute-esm-output-dir $scenario_dir/dist/esm --absolute-cjs-output-dir $scenario_dir/dist/cjs
+      """
+    Then the result is ok
+    And the exact content for "dist/esm/package.json" should be:
+      """
+      {
+        "type": "module"
+      }
+      """
+    And the exact content for "dist/cjs/package.json" should be:
+      """
+      {
+        "type": "commonjs"
+      }
+      """
+
+  Scenario: When no output directory is given, it is an error
+    Given there is an npm package with:
+      """
+      {
+        "name": "test",
+        "version": "1.0.0"
+      }
+      """
+    When the following command is executed:
+      """
+      enforce-type --absolute-package-dir $scenario_dir
+      """
+    Then the result is error and equals the following text:
+      """
+      No output directory found; use --absolute-esm-output-dir <path> and/or --absolute-cjs-output-dir <path>
+      """
+
+  Scenario: When an output directory has a package.json that isn't a marker, it is an error
+    Given there is an npm package with:
+      """
+      {
+        "name": "test",
+        "version": "1.0.0"
+      }
+      """
+    And there is a file named "dist/esm/package.json" with:
+      """
+      {
+        "name": "test-esm",
+        "private": true
+      }
+      """
+    When the following command is executed:
+      """
+      enforce-type --absolute-package-dir $scenario_dir --absolute-esm-output-dir $scenario_dir/dist/esm
+      """
+    Then the result is error and equals the following text:
+      """
+      Failed to write $scenario_dir/dist/esm/package.json: its package.json describes a package rather than marking a module format
+      """
+
+  Scenario: When an output directory doesn't exist, it is an error
+    Given there is an npm package with:
+      """
+      {
+        "name": "test",
+        "version": "1.0.0"
+      }
+      """
+    When the following command is executed:
+      """
+      enforce-type --absolute-package-dir $scenario_dir --absolute-esm-output-dir $scenario_dir/dist/esm
+      """
+    Then the result is error and equals the following text:
+      """
+      AbsoluteOutputDir '$scenario_dir/dist/esm' not found
+      """
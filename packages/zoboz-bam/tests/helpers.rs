@@ -1,6 +1,7 @@
 use cucumber::gherkin::Step;
 use cucumber::World;
-use std::{fs, path::Path};
+use flate2::read::GzDecoder;
+use std::{fs, io::Read, path::Path};
 use tempfile::{tempdir, TempDir};
 
 #[derive(Debug, Default, World)]
@@ -38,7 +39,52 @@ pub fn initiate_tempdir(world: &mut TheWorld) {
     world.tempdir = Some(tempdir().unwrap());
 }
 
+/// Spells out what docstrings can't hold: `\t` is a tab, `\r` a carriage return, and a last line
+/// of `\ No newline at end of file` drops the final line break, the way diffs mark it.
+pub fn decode_bytes(docstring: &str) -> String {
+    let content = docstring.trim_matches('\n');
+    let content = match content.strip_suffix("\n\\ No newline at end of file") {
+        Some(content) => content.to_string(),
+        None => format!("{}\n", content),
+    };
+
+    content.replace("\\t", "\t").replace("\\r", "\r")
+}
+
 pub fn reformat_json(json: &str) -> String {
     let value: serde_json::Value = serde_json::from_str(json).unwrap();
     serde_json::to_string_pretty(&value).unwrap()
 }
+
+/// Lists the tarball entries as `path size` lines, in the order they were packed.
+pub fn read_tarball_entries(world: &TheWorld, file_name: &str) -> Vec<String> {
+    let path = get_dir_path(world).join(file_name);
+    let mut tar = vec![];
+    GzDecoder::new(fs::File::open(path).unwrap())
+        .read_to_end(&mut tar)
+        .unwrap();
+
+    let mut entries = vec![];
+    let mut offset = 0;
+    while offset + 512 <= tar.len() && tar[offset] != 0 {
+        let header = &tar[offset..offset + 512];
+        let read_text = |range: std::ops::Range<usize>| {
+            String::from_utf8_lossy(&header[range])
+                .trim_matches(|c: char| c == '\0' || c == ' ')
+                .to_string()
+        };
+        let prefix = read_text(345..500);
+        let name = read_text(0..100);
+        let size = usize::from_str_radix(&read_text(124..136), 8).unwrap();
+        let path = if prefix.is_empty() {
+            name
+        } else {
+            format!("{}/{}", prefix, name)
+        };
+
+        entries.push(format!("{} {}", path, size));
+        offset += 512 + size.div_ceil(512) * 512;
+    }
+
+    entries
+}